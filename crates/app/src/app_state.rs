@@ -1,15 +1,55 @@
-use std::sync::Weak;
+use std::sync::{Arc, Weak};
 
-use gpui::{AppContext, Global};
+use gpui::{AppContext, AssetSource, Global, Model};
+use workspace::WorkspaceStore;
 
-pub struct AppState {}
+use crate::{db::Db, keymap::Keymap, paths, settings::Settings};
 
-struct GlobalAppState();
+/// The shared services of the app.
+pub struct AppState {
+    pub settings: Model<Settings>,
+    pub keymap: Keymap,
+    pub workspace_store: Model<WorkspaceStore>,
+    pub db: Arc<Db>,
+    /// The assets the app was built with, for views that load raw files.
+    pub assets: Arc<dyn AssetSource>,
+}
+
+struct GlobalAppState(Weak<AppState>);
 
 impl Global for GlobalAppState {}
 
 impl AppState {
-    pub fn set_global(_app_state: Weak<AppState>, cx: &mut AppContext) {
-        cx.set_global(GlobalAppState());
+    /// Load the settings, keymap and database from the config directory.
+    ///
+    /// Invalid settings, keymap or database files are logged and replaced with the defaults.
+    /// `assets` should be the source given to the app.
+    pub fn new(assets: Arc<dyn AssetSource>, cx: &mut AppContext) -> Self {
+        let settings = Settings::load(&paths::settings_file()).unwrap_or_else(|err| {
+            log::error!("{:?}", err);
+            Settings::default()
+        });
+        let keymap = Keymap::load(&paths::keymap_file()).unwrap_or_else(|err| {
+            log::error!("{:?}", err);
+            Keymap::default()
+        });
+        let db = Db::open(paths::db_file());
+
+        Self {
+            settings: cx.new_model(|_| settings),
+            keymap,
+            workspace_store: WorkspaceStore::global(cx),
+            db: Arc::new(db),
+            assets,
+        }
+    }
+
+    pub fn set_global(app_state: Weak<AppState>, cx: &mut AppContext) {
+        cx.set_global(GlobalAppState(app_state));
+    }
+
+    /// Returns the app state, unless it has been dropped or never set.
+    pub fn global(cx: &AppContext) -> Option<Arc<Self>> {
+        cx.try_global::<GlobalAppState>()?.0.upgrade()
     }
 }
@@ -1,6 +1,8 @@
+use std::{borrow::Cow, sync::Arc};
+
 use anyhow::anyhow;
 
-use gpui::AssetSource;
+use gpui::{AssetSource, SharedString};
 use rust_embed::RustEmbed;
 
 #[derive(RustEmbed)]
@@ -29,3 +31,17 @@ impl AssetSource for Assets {
             .collect())
     }
 }
+
+/// An asset source shared by the app and [`AppState`](crate::app_state::AppState).
+#[derive(Clone)]
+pub struct SharedAssets(pub Arc<dyn AssetSource>);
+
+impl AssetSource for SharedAssets {
+    fn load(&self, path: &str) -> gpui::Result<Option<Cow<'static, [u8]>>> {
+        self.0.load(path)
+    }
+
+    fn list(&self, path: &str) -> gpui::Result<Vec<SharedString>> {
+        self.0.list(path)
+    }
+}
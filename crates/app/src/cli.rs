@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::settings::Appearance;
+
+pub const USAGE: &str = "\
+Usage: gpui-app [OPTIONS]
+
+Options:
+    --story <NAME>      Open a window with only the given story
+    --layout <FILE>     Open the stories of a JSON pane layout file
+    --theme <THEME>     Use the `light` or `dark` theme
+    --locale <LOCALE>   Use the given locale, e.g. `en` or `zh-CN`
+    --new-window        Also open an empty window
+    --list-stories      Print the available stories and exit
+    -h, --help          Print this help and exit";
+
+/// The command-line arguments of the app.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Cli {
+    pub story: Option<String>,
+    pub layout: Option<PathBuf>,
+    pub theme: Option<Appearance>,
+    pub locale: Option<String>,
+    pub new_window: bool,
+    pub list_stories: bool,
+    pub help: bool,
+}
+
+impl Cli {
+    /// Parse the arguments, without the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            // Accept both `--flag value` and `--flag=value`.
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => {
+                    (flag.to_string(), Some(value.to_string()))
+                }
+                _ => (arg, None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next())
+                    .ok_or_else(|| anyhow!("{flag} requires a value"))
+            };
+
+            match flag.as_str() {
+                "--story" => cli.story = Some(value()?),
+                "--layout" => cli.layout = Some(value()?.into()),
+                "--theme" => {
+                    cli.theme = Some(match value()?.as_str() {
+                        "light" => Appearance::Light,
+                        "dark" => Appearance::Dark,
+                        theme => bail!("unknown theme {theme:?}, expected `light` or `dark`"),
+                    })
+                }
+                "--locale" => cli.locale = Some(value()?),
+                "--new-window" => cli.new_window = true,
+                "--list-stories" => cli.list_stories = true,
+                "-h" | "--help" => cli.help = true,
+                _ => bail!("unknown argument {flag:?}"),
+            }
+        }
+
+        if cli.story.is_some() && cli.layout.is_some() {
+            bail!("--story and --layout can't be used together");
+        }
+
+        Ok(cli)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Cli> {
+        Cli::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        assert_eq!(parse(&[]).unwrap(), Cli::default());
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(
+            parse(&["--story", "Buttons"]).unwrap().story.as_deref(),
+            Some("Buttons")
+        );
+        assert_eq!(
+            parse(&["--layout", "layout.json"]).unwrap().layout,
+            Some(PathBuf::from("layout.json"))
+        );
+        assert_eq!(
+            parse(&["--theme", "light"]).unwrap().theme,
+            Some(Appearance::Light)
+        );
+        assert_eq!(
+            parse(&["--theme", "dark"]).unwrap().theme,
+            Some(Appearance::Dark)
+        );
+        assert_eq!(
+            parse(&["--locale", "zh-CN"]).unwrap().locale.as_deref(),
+            Some("zh-CN")
+        );
+        assert!(parse(&["--new-window"]).unwrap().new_window);
+        assert!(parse(&["--list-stories"]).unwrap().list_stories);
+        assert!(parse(&["--help"]).unwrap().help);
+        assert!(parse(&["-h"]).unwrap().help);
+
+        assert_eq!(
+            parse(&["--story=List", "--theme=dark", "--new-window"]).unwrap(),
+            Cli {
+                story: Some("List".into()),
+                theme: Some(Appearance::Dark),
+                new_window: true,
+                ..Cli::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        // Unknown flags and values.
+        assert!(parse(&["--stories"]).is_err());
+        assert!(parse(&["Buttons"]).is_err());
+        assert!(parse(&["--theme", "blue"]).is_err());
+
+        // Missing values.
+        assert!(parse(&["--story"]).is_err());
+        assert!(parse(&["--locale"]).is_err());
+        assert!(parse(&["--layout"]).is_err());
+
+        assert!(parse(&["--story", "List", "--layout", "layout.json"]).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use anyhow::{Context as _, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// A small key-value store, persisted as a JSON file.
+pub struct Db {
+    path: PathBuf,
+    values: Mutex<BTreeMap<String, Value>>,
+}
+
+impl Db {
+    /// Open the store at the given path, starting empty if the file doesn't exist.
+    ///
+    /// A file that can't be read is logged and moved aside, see [`Db::backup_path`], and the
+    /// store starts empty, as settings and keymap fall back to their defaults.
+    pub fn open(path: PathBuf) -> Self {
+        let values = Self::load(&path).unwrap_or_else(|err| {
+            log::error!("{:?}", err);
+            let backup = Self::backup_path(&path);
+            match fs::rename(&path, &backup) {
+                Ok(()) => log::warn!("moved {} to {}", path.display(), backup.display()),
+                Err(err) => log::error!("failed to move {} aside: {}", path.display(), err),
+            }
+            BTreeMap::new()
+        });
+
+        Self {
+            path,
+            values: Mutex::new(values),
+        }
+    }
+
+    fn load(path: &Path) -> Result<BTreeMap<String, Value>> {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    /// Where a file that can't be read is moved, e.g. `db.json.bak` for `db.json`.
+    pub fn backup_path(path: &Path) -> PathBuf {
+        let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+        file_name.push(".bak");
+        path.with_file_name(file_name)
+    }
+
+    pub fn read<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let values = self.values.lock().unwrap();
+        let value = values.get(key)?.clone();
+        serde_json::from_value(value)
+            .map_err(|err| log::error!("failed to read {} from db: {}", key, err))
+            .ok()
+    }
+
+    pub fn write<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        let mut values = self.values.lock().unwrap();
+        values.insert(key.to_string(), serde_json::to_value(value)?);
+        self.flush(&values)
+    }
+
+    fn flush(&self, values: &BTreeMap<String, Value>) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, serde_json::to_string_pretty(values)?)
+            .with_context(|| format!("failed to write {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    /// An empty directory for the files of a test.
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("gpui-app-db-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_read_write() {
+        let path = test_dir("read-write").join("db.json");
+        let db = Db::open(path.clone());
+        assert_eq!(db.read::<String>("locale"), None);
+
+        db.write("locale", &"zh-CN".to_string()).unwrap();
+        assert_eq!(db.read::<String>("locale").as_deref(), Some("zh-CN"));
+        // A value of another type is logged and ignored.
+        assert_eq!(db.read::<u32>("locale"), None);
+
+        let db = Db::open(path);
+        assert_eq!(db.read::<String>("locale").as_deref(), Some("zh-CN"));
+    }
+
+    #[test]
+    fn test_corrupt_file_is_moved_aside() {
+        let path = test_dir("corrupt").join("db.json");
+        fs::write(&path, "{ not json").unwrap();
+
+        let db = Db::open(path.clone());
+        assert_eq!(db.read::<String>("locale"), None);
+        assert!(!path.exists());
+        let backup = Db::backup_path(&path);
+        assert_eq!(backup.file_name().unwrap(), "db.json.bak");
+        assert_eq!(fs::read_to_string(&backup).unwrap(), "{ not json");
+
+        // The store still works, and writes a new file.
+        db.write("locale", &"en".to_string()).unwrap();
+        assert_eq!(
+            Db::open(path).read::<String>("locale").as_deref(),
+            Some("en")
+        );
+    }
+}
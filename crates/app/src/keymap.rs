@@ -0,0 +1,77 @@
+use std::{fs, io, path::Path};
+
+use anyhow::{Context as _, Result};
+use gpui::{AppContext, KeyBinding};
+use serde::Deserialize;
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct KeymapBinding {
+    /// Keystrokes separated by spaces, e.g. `cmd-k cmd-s`.
+    pub keystrokes: String,
+    /// The action name, e.g. `main_menu::Quit`.
+    pub action: String,
+    pub context: Option<String>,
+}
+
+/// The key bindings of the app, the defaults followed by the user's `keymap.json`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Keymap {
+    pub bindings: Vec<KeymapBinding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let binding = |keystrokes: &str, action: &str| KeymapBinding {
+            keystrokes: keystrokes.into(),
+            action: action.into(),
+            context: None,
+        };
+
+        Self {
+            bindings: vec![
+                binding("cmd-q", "main_menu::Quit"),
+                binding("cmd-shift-n", "workspace::NewWindow"),
+            ],
+        }
+    }
+}
+
+impl Keymap {
+    /// Load the default keymap, extended with the bindings of the given file if it exists.
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut keymap = Self::default();
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                let bindings: Vec<KeymapBinding> = serde_json::from_str(&content)
+                    .with_context(|| format!("failed to parse {}", path.display()))?;
+                keymap.bindings.extend(bindings);
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to read {}", path.display()))
+            }
+        }
+        Ok(keymap)
+    }
+
+    /// Bind all keys, skipping the bindings of unknown actions.
+    pub fn bind(&self, cx: &mut AppContext) {
+        let bindings = self
+            .bindings
+            .iter()
+            .filter_map(|binding| {
+                let action = cx
+                    .build_action(&binding.action, None)
+                    .with_context(|| format!("unknown action {}", binding.action))
+                    .map_err(|err| log::error!("{:?}", err))
+                    .ok()?;
+                KeyBinding::load(&binding.keystrokes, action, binding.context.as_deref())
+                    .map_err(|err| {
+                        log::error!("invalid keystrokes {}: {}", binding.keystrokes, err)
+                    })
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        cx.bind_keys(bindings);
+    }
+}
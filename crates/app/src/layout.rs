@@ -0,0 +1,105 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context as _, Result};
+use gpui::{AppContext, View, WindowContext};
+use serde::{Deserialize, Serialize};
+use story::{StoryContainer, StoryRegistry};
+use workspace::{
+    pane_group::{PaneLayout, PaneLayoutSpec},
+    Workspace,
+};
+
+/// The stories opened in a pane of a [`SerializedLayout`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SerializedPane {
+    stories: Vec<String>,
+    /// The story of the active tab, the last one when unset.
+    #[serde(default)]
+    active: Option<String>,
+}
+
+/// A pane layout saved to a file, naming the stories opened in each pane.
+///
+/// ```json
+/// {
+///     "axis": "horizontal",
+///     "flexes": [1, 2],
+///     "members": [{ "stories": ["Buttons", "Input"] }, { "stories": ["List"] }]
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SerializedLayout(PaneLayout<SerializedPane>);
+
+impl SerializedLayout {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    /// The layout of the center panes of the workspace and their stories.
+    pub fn from_workspace(workspace: &Workspace, cx: &AppContext) -> Self {
+        let story_name = |item: &Box<dyn workspace::item::ItemHandle>| {
+            item.downcast::<StoryContainer>()
+                .map(|story| story.read(cx).name().to_string())
+        };
+
+        Self(workspace.center().layout().map(&mut |pane_id| {
+            let pane = workspace
+                .panes()
+                .iter()
+                .find(|pane| pane.entity_id() == *pane_id)
+                .map(|pane| pane.read(cx));
+
+            SerializedPane {
+                stories: pane
+                    .map(|pane| pane.items().filter_map(story_name).collect())
+                    .unwrap_or_default(),
+                active: pane
+                    .and_then(|pane| pane.active_item())
+                    .and_then(|item| story_name(&item)),
+            }
+        }))
+    }
+
+    pub fn has_stories(&self) -> bool {
+        self.0.panes().iter().any(|pane| !pane.stories.is_empty())
+    }
+
+    /// Replace the center panes of the workspace with this layout and open its stories.
+    ///
+    /// Unknown stories are skipped, e.g. when a restored layout names a story that is gone.
+    pub fn open(&self, workspace: &View<Workspace>, cx: &mut WindowContext) -> Result<()> {
+        // A new pane for each pane of the layout.
+        let layout: PaneLayoutSpec = self.0.map(&mut |_| None);
+        workspace.update(cx, |workspace, cx| workspace.set_center_layout(layout, cx))?;
+
+        let pane_ids = workspace.read(cx).center().layout().pane_ids();
+        let panes = workspace.read(cx).panes().to_vec();
+        for (pane_id, serialized) in pane_ids.into_iter().zip(self.0.panes()) {
+            let Some(pane) = panes.iter().find(|pane| pane.entity_id() == pane_id) else {
+                continue;
+            };
+            let mut opened = Vec::new();
+            for name in &serialized.stories {
+                match StoryRegistry::find(name, cx) {
+                    Some(story) => {
+                        story.open(pane, cx);
+                        opened.push(name);
+                    }
+                    None => log::warn!("skipping unknown story {:?}", name),
+                }
+            }
+            if let Some(ix) = serialized.active.as_ref().and_then(|active| {
+                opened
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(active))
+            }) {
+                pane.update(cx, |pane, cx| pane.activate_item(ix, true, false, cx));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,33 +1,112 @@
-use std::sync::Arc;
+use std::{borrow::Cow, env, process, sync::Arc};
 
 use anyhow::Result;
 use app_state::AppState;
-use assets::Assets;
-use gpui::{actions, App, AppContext, KeyBinding, Menu, MenuItem};
+use assets::{Assets, SharedAssets};
+use cli::Cli;
+use gpui::{actions, App, AppContext, AssetSource, Menu, MenuItem};
+use layout::SerializedLayout;
+use story::{RegisterStories, StoryRegistry};
+use story_workspace::{WindowContent, LAYOUT_KEY};
 use ti::input::{Copy, Cut, Paste, Redo, Undo};
+use workspace::{ActivateWindow, MoveItemToWindow, NewWindow, WorkspaceStore};
 
 mod app_state;
 mod assets;
+mod cli;
+mod db;
+mod keymap;
+mod layout;
+mod paths;
+mod settings;
 mod story_workspace;
 
 actions!(main_menu, [Quit]);
 
+/// The registration hooks of the crates with stories, a crate adding stories adds its hook here.
+const STORY_HOOKS: &[RegisterStories] = &[story::register_stories];
+
 fn init(app_state: Arc<AppState>, cx: &mut AppContext) -> Result<()> {
+    load_fonts(&app_state, cx)?;
     story_workspace::init(app_state.clone(), cx);
 
-    cx.bind_keys([KeyBinding::new("cmd-q", Quit, None)]);
+    app_state.keymap.bind(cx);
 
     Ok(())
 }
 
+/// Register the fonts shipped in the assets.
+fn load_fonts(app_state: &AppState, cx: &mut AppContext) -> Result<()> {
+    let fonts = app_state
+        .assets
+        .list("fonts")?
+        .iter()
+        .filter(|path| path.ends_with(".ttf"))
+        .filter_map(|path| app_state.assets.load(path).transpose())
+        .collect::<Result<Vec<Cow<'static, [u8]>>>>()?;
+    cx.text_system().add_fonts(fonts)
+}
+
 fn main() {
-    let app_state = Arc::new(AppState {});
+    let cli = match Cli::parse(env::args().skip(1)) {
+        Ok(cli) => cli,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            process::exit(2);
+        }
+    };
+    if cli.help {
+        println!("{}", cli::USAGE);
+        return;
+    }
+
+    // The registration hooks don't need a display, so the stories are listed and checked before
+    // the app starts, and the same registry becomes the one of the app.
+    let stories = StoryRegistry::new(STORY_HOOKS);
+    if cli.list_stories {
+        for story in stories.entries() {
+            println!("{}\t{}\t{}", story.name, story.category, story.description);
+        }
+        return;
+    }
+    if let Some(name) = &cli.story {
+        if stories.get(name).is_none() {
+            eprintln!("error: unknown story {:?}, see --list-stories", name);
+            process::exit(2);
+        }
+    }
+
+    let layout = match cli
+        .layout
+        .as_deref()
+        .map(SerializedLayout::load)
+        .transpose()
+    {
+        Ok(layout) => layout,
+        Err(e) => {
+            eprintln!("error: {:?}", e);
+            process::exit(2);
+        }
+    };
 
-    let app = App::new().with_assets(Assets);
+    let assets: Arc<dyn AssetSource> = Arc::new(Assets);
+    let app = App::new().with_assets(SharedAssets(assets.clone()));
 
     app.run(move |cx| {
+        stories.set_global(cx);
+        let app_state = Arc::new(AppState::new(assets, cx));
         AppState::set_global(Arc::downgrade(&app_state), cx);
 
+        // The command line wins over the settings.
+        app_state.settings.update(cx, |settings, _| {
+            if let Some(theme) = cli.theme {
+                settings.theme = Some(theme);
+            }
+            if let Some(locale) = cli.locale.clone() {
+                settings.locale = Some(locale);
+            }
+        });
+
         if let Err(e) = init(app_state.clone(), cx) {
             log::error!("{}", e);
             return;
@@ -35,32 +114,97 @@ fn main() {
 
         cx.on_action(quit);
 
-        cx.set_menus(vec![
-            Menu {
-                name: "GPUI App".into(),
-                items: vec![MenuItem::action("Quit", Quit)],
-            },
-            Menu {
-                name: "Edit".into(),
-                items: vec![
-                    MenuItem::os_action("Undo", Undo, gpui::OsAction::Undo),
-                    MenuItem::os_action("Redo", Redo, gpui::OsAction::Redo),
-                    MenuItem::separator(),
-                    MenuItem::os_action("Cut", Cut, gpui::OsAction::Cut),
-                    MenuItem::os_action("Copy", Copy, gpui::OsAction::Copy),
-                    MenuItem::os_action("Paste", Paste, gpui::OsAction::Paste),
-                ],
-            },
-        ]);
+        cx.set_menus(app_menus(cx));
+        // The Window menu lists the open windows, by the title of their active item.
+        cx.observe(&app_state.workspace_store, |_, cx| {
+            cx.set_menus(app_menus(cx))
+        })
+        .detach();
         cx.activate(true);
 
-        story_workspace::open_new(app_state.clone(), cx, |_workspace, _cx| {
+        let content = match (cli.story, layout) {
+            (Some(story), _) => WindowContent::Story(story),
+            (_, Some(layout)) => WindowContent::Layout(layout),
+            // Restore the stories of the last session.
+            _ => match app_state.db.read::<SerializedLayout>(LAYOUT_KEY) {
+                Some(layout) if layout.has_stories() => WindowContent::Layout(layout),
+                _ => WindowContent::AllStories,
+            },
+        };
+        story_workspace::open_new(content, cx, |_workspace, _cx| {
             // do something
         })
         .detach();
+
+        if cli.new_window {
+            story_workspace::open_new(WindowContent::Empty, cx, |_, _| {}).detach();
+        }
     });
 }
 
+fn app_menus(cx: &AppContext) -> Vec<Menu> {
+    vec![
+        Menu {
+            name: "GPUI App".into(),
+            items: vec![MenuItem::action("Quit", Quit)],
+        },
+        Menu {
+            name: "Edit".into(),
+            items: vec![
+                MenuItem::os_action("Undo", Undo, gpui::OsAction::Undo),
+                MenuItem::os_action("Redo", Redo, gpui::OsAction::Redo),
+                MenuItem::separator(),
+                MenuItem::os_action("Cut", Cut, gpui::OsAction::Cut),
+                MenuItem::os_action("Copy", Copy, gpui::OsAction::Copy),
+                MenuItem::os_action("Paste", Paste, gpui::OsAction::Paste),
+            ],
+        },
+        window_menu(cx),
+    ]
+}
+
+fn window_menu(cx: &AppContext) -> Menu {
+    // The windows are listed by position, but the actions address them by id, so a menu
+    // built before a window closes still targets the right windows.
+    let windows = WorkspaceStore::workspaces(cx)
+        .iter()
+        .enumerate()
+        .map(|(ix, (window, workspace))| {
+            let title = workspace
+                .upgrade()
+                .and_then(|workspace| workspace.read(cx).title(cx));
+            let title = match title {
+                Some(title) => format!("{}. {}", ix + 1, title),
+                None => format!("Window {}", ix + 1),
+            };
+            (WorkspaceStore::window_id(*window), title)
+        })
+        .collect::<Vec<_>>();
+
+    let mut items = vec![MenuItem::action("New Window", NewWindow)];
+    if !windows.is_empty() {
+        items.push(MenuItem::separator());
+        items.extend(
+            windows
+                .iter()
+                .map(|(id, title)| MenuItem::action(title.clone(), ActivateWindow(*id))),
+        );
+        items.push(MenuItem::separator());
+        items.push(MenuItem::submenu(Menu {
+            name: "Move Item to Window".into(),
+            items: windows
+                .iter()
+                .map(|(id, title)| MenuItem::action(title.clone(), MoveItemToWindow(*id)))
+                .collect(),
+        }));
+    }
+
+    Menu {
+        name: "Window".into(),
+        items,
+    }
+}
+
 fn quit(_: &Quit, cx: &mut AppContext) {
     cx.quit();
 }
@@ -0,0 +1,34 @@
+use std::{env, path::PathBuf};
+
+/// The directory holding the settings, keymap and database of the app.
+///
+/// Can be overridden with the `GPUI_APP_CONFIG_DIR` environment variable.
+pub fn config_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("GPUI_APP_CONFIG_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    if cfg!(target_os = "windows") {
+        if let Some(dir) = env::var_os("APPDATA") {
+            return PathBuf::from(dir).join("gpui-app");
+        }
+    }
+
+    env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(env::temp_dir)
+        .join(".config")
+        .join("gpui-app")
+}
+
+pub fn settings_file() -> PathBuf {
+    config_dir().join("settings.json")
+}
+
+pub fn keymap_file() -> PathBuf {
+    config_dir().join("keymap.json")
+}
+
+pub fn db_file() -> PathBuf {
+    config_dir().join("db.json")
+}
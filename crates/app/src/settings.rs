@@ -0,0 +1,54 @@
+use std::{fs, io, path::Path};
+
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+use ti::theme::ThemeMode;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Appearance {
+    Light,
+    Dark,
+}
+
+impl From<Appearance> for ThemeMode {
+    fn from(appearance: Appearance) -> Self {
+        match appearance {
+            Appearance::Light => ThemeMode::Light,
+            Appearance::Dark => ThemeMode::Dark,
+        }
+    }
+}
+
+/// User settings, read from `settings.json` in the config directory.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Follows the system appearance when unset.
+    pub theme: Option<Appearance>,
+    pub locale: Option<String>,
+    /// Quit when the last window is closed, instead of keeping the app running.
+    pub quit_on_last_window_closed: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: None,
+            locale: None,
+            quit_on_last_window_closed: true,
+        }
+    }
+}
+
+impl Settings {
+    /// Load the settings from the given file, a missing file gives the default settings.
+    pub fn load(path: &Path) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use gpui::*;
 use prelude::FluentBuilder as _;
 use private::serde::Deserialize;
-use story::{
-    ButtonStory, IconStory, InputStory, ListStory, PopupStory, ScrollableStory, StoryContainer,
-    SwitchStory,
+use story::{open_story, StoryNavigator, StoryRegistry};
+use workspace::{
+    item::ItemHandle, ActivateWindow, LastWindowClosed, NewWindow, TitleBar, Workspace,
+    WorkspaceStore,
 };
-use workspace::{TitleBar, Workspace};
 
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use ti::{
     button::Button,
     drawer::Drawer,
@@ -19,115 +19,199 @@ use ti::{
     ContextModal, IconName, Root, Sizable,
 };
 
-use crate::app_state::AppState;
+use crate::{app_state::AppState, layout::SerializedLayout};
 
 #[derive(Clone, PartialEq, Eq, Deserialize)]
 struct SelectLocale(SharedString);
 
 impl_actions!(locale_switcher, [SelectLocale]);
 
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+struct OpenStory(SharedString);
+
+impl_actions!(story_picker, [OpenStory]);
+
 actions!(workspace, [Open, CloseWindow]);
 
-pub fn init(_app_state: Arc<AppState>, cx: &mut AppContext) {
+const LOCALE_KEY: &str = "locale";
+/// The key of the center layout restored on the next launch.
+pub const LAYOUT_KEY: &str = "layout";
+/// How long the layout must stay unchanged before it is saved.
+const SAVE_LAYOUT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+pub fn init(app_state: Arc<AppState>, cx: &mut AppContext) {
     cx.on_action(|_action: &Open, _cx: &mut AppContext| {});
+    cx.on_action(|_: &NewWindow, cx: &mut AppContext| {
+        open_new(WindowContent::AllStories, cx, |_, _| {}).detach();
+    });
+    cx.on_action(|action: &ActivateWindow, cx: &mut AppContext| {
+        WorkspaceStore::activate_window(action.0, cx);
+    });
+    // Reopen a window when the app is kept running after closing the last one.
+    cx.on_reopen(|cx| {
+        if WorkspaceStore::workspaces(cx).is_empty() {
+            open_new(WindowContent::AllStories, cx, |_, _| {}).detach();
+        }
+    });
 
     Theme::init(cx);
     ti::init(cx);
+    components::init(cx);
     story::init(cx);
+
+    let settings = app_state.settings.read(cx).clone();
+    if let Some(theme) = settings.theme {
+        Theme::change(theme.into(), cx);
+    }
+    // The locale of the settings wins over the one last picked in the title bar.
+    if let Some(locale) = settings
+        .locale
+        .or_else(|| app_state.db.read::<String>(LOCALE_KEY))
+    {
+        ti::set_locale(&locale);
+    }
+    let last_window_closed = if settings.quit_on_last_window_closed {
+        LastWindowClosed::Quit
+    } else {
+        LastWindowClosed::KeepRunning
+    };
+    app_state.workspace_store.update(cx, |store, _| {
+        store.set_last_window_closed(last_window_closed);
+    });
+}
+
+/// What a new window opens with.
+pub enum WindowContent {
+    /// One tab for each story.
+    AllStories,
+    /// A single story, by name.
+    Story(String),
+    /// Stories arranged in panes.
+    Layout(SerializedLayout),
+    /// An item dragged out of another window.
+    Item(Box<dyn ItemHandle>),
+    Empty,
 }
 
 pub struct StoryWorkspace {
     workspace: View<Workspace>,
     locale_selector: View<LocaleSelector>,
+    story_picker: View<StoryPicker>,
+    _save_layout_task: Task<()>,
 }
 
 impl StoryWorkspace {
     pub fn new(
-        _app_state: Arc<AppState>,
         workspace: View<Workspace>,
+        content: WindowContent,
         cx: &mut ViewContext<Self>,
     ) -> Self {
         cx.observe_window_appearance(|_workspace, cx| {
-            Theme::sync_system_appearance(cx);
+            // Only follow the system when the theme is not set in the settings.
+            let theme =
+                AppState::global(cx).and_then(|app_state| app_state.settings.read(cx).theme);
+            if theme.is_none() {
+                Theme::sync_system_appearance(cx);
+            }
         })
         .detach();
 
-        StoryContainer::add_pane(
-            "Buttons",
-            "Displays a button or a component that looks like a button.",
-            ButtonStory::view(cx).into(),
-            workspace.clone(),
-            cx,
-        )
-        .detach();
-
-        StoryContainer::add_pane(
-            "Input",
-            "A control that allows the user to input text.",
-            InputStory::view(cx).into(),
-            workspace.clone(),
-            cx,
-        )
-        .detach();
-
-        StoryContainer::add_pane(
-            "Switch",
-            "A control that allows the user to toggle between two states.",
-            SwitchStory::view(cx).into(),
-            workspace.clone(),
-            cx,
-        )
-        .detach();
-
-        StoryContainer::add_pane(
-            "Popup",
-            "A popup displays content on top of the main page.",
-            PopupStory::view(cx).into(),
-            workspace.clone(),
-            cx,
+        cx.subscribe(
+            &workspace,
+            |this, _, event: &workspace::Event, cx| match event {
+                workspace::Event::ItemTornOff { item, position } => {
+                    let bounds = Bounds::new(*position, size(px(800.), px(600.)));
+                    Self::open_window(bounds, WindowContent::Item(item.boxed_clone()), cx).detach();
+                }
+                workspace::Event::PaneAdded(_)
+                | workspace::Event::PaneRemoved
+                | workspace::Event::ItemAdded
+                | workspace::Event::ItemRemoved
+                | workspace::Event::ActiveItemChanged => this.save_layout(cx),
+                _ => {}
+            },
         )
         .detach();
+        // The layout restored on the next launch is the one of the last focused window.
+        cx.observe_window_activation(|this, cx| this.save_layout(cx))
+            .detach();
 
-        StoryContainer::add_pane(
-            "List",
-            "A list displays a series of items.",
-            ListStory::view(cx).into(),
-            workspace.clone(),
-            cx,
-        )
-        .detach();
+        StoryNavigator::add_panel(workspace.clone(), cx);
 
-        StoryContainer::add_pane(
-            "Icon",
-            "Icon use examples",
-            IconStory::view(cx).into(),
-            workspace.clone(),
-            cx,
-        )
-        .detach();
-
-        StoryContainer::add_pane(
-            "Scrollable",
-            "A scrollable area with scroll bar.",
-            ScrollableStory::view(cx).into(),
-            workspace.clone(),
-            cx,
-        )
-        .detach();
+        let pane = workspace.read(cx).active_pane().clone();
+        match content {
+            WindowContent::AllStories => Self::add_stories(&pane, cx),
+            WindowContent::Story(name) => open_story(&name, &workspace, cx),
+            WindowContent::Layout(layout) => {
+                if let Err(err) = layout.open(&workspace, cx) {
+                    log::error!("failed to open layout: {:?}", err);
+                }
+            }
+            WindowContent::Item(item) => pane.update(cx, |pane, cx| {
+                pane.add_item(item, true, true, None, cx);
+            }),
+            WindowContent::Empty => {}
+        }
 
         let locale_selector = cx.new_view(LocaleSelector::new);
+        let story_picker = cx.new_view(|cx| StoryPicker::new(workspace.downgrade(), cx));
         Self {
             workspace,
             locale_selector,
+            story_picker,
+            _save_layout_task: Task::ready(()),
+        }
+    }
+
+    fn add_stories(pane: &View<workspace::pane::Pane>, cx: &mut ViewContext<Self>) {
+        for story in StoryRegistry::stories(cx) {
+            story.open(pane, cx);
         }
     }
 
+    /// Remember the center layout of the focused window, so the next launch restores it.
+    ///
+    /// The layout is written once it stops changing, e.g. after the stories are opened at
+    /// startup, and off the main thread.
+    fn save_layout(&mut self, cx: &mut ViewContext<Self>) {
+        if !cx.is_window_active() {
+            return;
+        }
+        let Some(app_state) = AppState::global(cx) else {
+            return;
+        };
+
+        self._save_layout_task = cx.spawn(|this, mut cx| async move {
+            Timer::after(SAVE_LAYOUT_DEBOUNCE).await;
+            let Ok(layout) = this.update(&mut cx, |this, cx| {
+                SerializedLayout::from_workspace(this.workspace.read(cx), cx)
+            }) else {
+                return;
+            };
+            let result = cx
+                .background_executor()
+                .spawn(async move { app_state.db.write(LAYOUT_KEY, &layout) })
+                .await;
+            if let Err(err) = result {
+                log::error!("failed to save layout: {:?}", err);
+            }
+        });
+    }
+
     pub fn new_local(
-        app_state: Arc<AppState>,
+        content: WindowContent,
         cx: &mut AppContext,
     ) -> Task<anyhow::Result<WindowHandle<Root>>> {
         let window_bounds = Bounds::centered(None, size(px(1600.0), px(1200.0)), cx);
+        Self::open_window(window_bounds, content, cx)
+    }
 
+    /// Open a window with a fresh workspace.
+    fn open_window(
+        window_bounds: Bounds<Pixels>,
+        content: WindowContent,
+        cx: &mut AppContext,
+    ) -> Task<anyhow::Result<WindowHandle<Root>>> {
         cx.spawn(|mut cx| async move {
             let options = WindowOptions {
                 window_bounds: Some(WindowBounds::Windowed(window_bounds)),
@@ -146,7 +230,7 @@ impl StoryWorkspace {
 
             let window = cx.open_window(options, |cx| {
                 let workspace = cx.new_view(|cx| Workspace::new(None, cx));
-                let story_view = cx.new_view(|cx| Self::new(app_state.clone(), workspace, cx));
+                let story_view = cx.new_view(|cx| Self::new(workspace, content, cx));
                 cx.new_view(|cx| Root::new(story_view.into(), cx))
             })?;
 
@@ -154,11 +238,6 @@ impl StoryWorkspace {
                 .update(&mut cx, |_, cx| {
                     cx.activate_window();
                     cx.set_window_title("GPUI App");
-                    cx.on_release(|_, _, cx| {
-                        // exit app
-                        cx.quit();
-                    })
-                    .detach();
                 })
                 .expect("failed to update window");
 
@@ -168,12 +247,12 @@ impl StoryWorkspace {
 }
 
 pub fn open_new(
-    app_state: Arc<AppState>,
+    content: WindowContent,
     cx: &mut AppContext,
     init: impl FnOnce(&mut Root, &mut ViewContext<Root>) + 'static + Send,
 ) -> Task<()> {
     let task: Task<std::result::Result<WindowHandle<Root>, anyhow::Error>> =
-        StoryWorkspace::new_local(app_state, cx);
+        StoryWorkspace::new_local(content, cx);
     cx.spawn(|mut cx| async move {
         if let Some(root) = task.await.ok() {
             root.update(&mut cx, |workspace, cx| init(workspace, cx))
@@ -216,6 +295,7 @@ impl Render for StoryWorkspace {
                             .justify_end()
                             .px_2()
                             .gap_2()
+                            .child(self.story_picker.clone())
                             .child(self.locale_selector.clone())
                             .child(
                                 Button::new("theme-mode", cx)
@@ -304,6 +384,11 @@ impl LocaleSelector {
 
     fn on_select_locale(&mut self, locale: &SelectLocale, cx: &mut ViewContext<Self>) {
         ti::set_locale(&locale.0);
+        if let Some(app_state) = AppState::global(cx) {
+            if let Err(err) = app_state.db.write(LOCALE_KEY, &locale.0.to_string()) {
+                log::error!("{:?}", err);
+            }
+        }
         cx.refresh();
     }
 }
@@ -338,3 +423,64 @@ impl Render for LocaleSelector {
             )
     }
 }
+
+/// Lists the registered stories by category, and opens or focuses the picked one.
+struct StoryPicker {
+    focus_handle: FocusHandle,
+    workspace: WeakView<Workspace>,
+}
+
+impl StoryPicker {
+    pub fn new(workspace: WeakView<Workspace>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            workspace,
+        }
+    }
+
+    fn on_open_story(&mut self, story: &OpenStory, cx: &mut ViewContext<Self>) {
+        if let Some(workspace) = self.workspace.upgrade() {
+            open_story(&story.0, &workspace, cx);
+        }
+    }
+}
+
+impl Render for StoryPicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focus_handle = self.focus_handle.clone();
+        let stories = StoryRegistry::stories(cx);
+
+        div()
+            .id("story-picker")
+            .track_focus(&focus_handle)
+            .on_action(cx.listener(Self::on_open_story))
+            .child(
+                Popover::new("story-picker")
+                    .anchor(AnchorCorner::TopRight)
+                    .trigger(
+                        Button::new("btn", cx)
+                            .small()
+                            .ghost()
+                            .icon(IconName::Search),
+                    )
+                    .content(move |cx| {
+                        let stories = stories.clone();
+                        PopupMenu::build(cx, move |mut this, _cx| {
+                            let categories = StoryRegistry::categories(&stories);
+                            for (ix, category) in categories.iter().enumerate() {
+                                if ix > 0 {
+                                    this = this.separator();
+                                }
+                                for story in stories.iter().filter(|s| &s.category == category) {
+                                    this = this.menu(
+                                        format!("{} / {}", category, story.name),
+                                        Box::new(OpenStory(story.name.clone())),
+                                    );
+                                }
+                            }
+                            this
+                        })
+                    }),
+            )
+    }
+}
@@ -0,0 +1,357 @@
+use std::{collections::VecDeque, time::Duration};
+
+use gpui::{
+    div, px, svg, AppContext, ClipboardItem, ElementId, EventEmitter, Global,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Task, Timer, ViewContext,
+};
+use serde::{Deserialize, Serialize};
+use ti::theme::ActiveTheme;
+
+/// The number of entries kept in the [`ClipboardHistory`].
+const HISTORY_CAPACITY: usize = 20;
+/// How long the [`Clipboard`] button shows that its content was copied.
+const COPIED_FEEDBACK: Duration = Duration::from_secs(2);
+
+pub fn init(cx: &mut AppContext) {
+    cx.set_global(ClipboardHistory::new(HISTORY_CAPACITY));
+}
+
+/// A typed clipboard payload.
+///
+/// Every payload is written with a plain text version, so it can be pasted in other
+/// applications. The type is kept in the metadata of the clipboard item.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClipboardContent {
+    Text(String),
+    /// Rich text, with its plain text version.
+    Html {
+        html: String,
+        text: String,
+    },
+    Json(serde_json::Value),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum ClipboardMetadata {
+    Html { html: String },
+    Json,
+}
+
+impl ClipboardContent {
+    /// The plain text version of the content.
+    pub fn text(&self) -> String {
+        match self {
+            Self::Text(text) | Self::Html { text, .. } => text.clone(),
+            Self::Json(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+        }
+    }
+
+    /// A short description of the type of the content.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Text(_) => "Text",
+            Self::Html { .. } => "HTML",
+            Self::Json(_) => "JSON",
+        }
+    }
+
+    pub fn to_item(&self) -> ClipboardItem {
+        let item = ClipboardItem::new(self.text());
+        match self {
+            Self::Text(_) => item,
+            Self::Html { html, .. } => {
+                item.with_metadata(ClipboardMetadata::Html { html: html.clone() })
+            }
+            Self::Json(_) => item.with_metadata(ClipboardMetadata::Json),
+        }
+    }
+
+    /// Read the content of an item, items written by other applications are plain text.
+    pub fn from_item(item: &ClipboardItem) -> Self {
+        let text = item.text().clone();
+        match item.metadata::<ClipboardMetadata>() {
+            Some(ClipboardMetadata::Html { html }) => Self::Html { html, text },
+            Some(ClipboardMetadata::Json) => match serde_json::from_str(&text) {
+                Ok(value) => Self::Json(value),
+                Err(_) => Self::Text(text),
+            },
+            None => Self::Text(text),
+        }
+    }
+}
+
+impl From<String> for ClipboardContent {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for ClipboardContent {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+impl From<serde_json::Value> for ClipboardContent {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+/// Where the clipboard items are stored, the platform clipboard of the app or a local one.
+pub trait ClipboardBackend {
+    fn write(&mut self, item: ClipboardItem);
+    fn read(&mut self) -> Option<ClipboardItem>;
+}
+
+impl ClipboardBackend for AppContext {
+    fn write(&mut self, item: ClipboardItem) {
+        self.write_to_clipboard(item);
+    }
+
+    fn read(&mut self) -> Option<ClipboardItem> {
+        self.read_from_clipboard()
+    }
+}
+
+/// A clipboard local to the process, not shared with other applications.
+#[derive(Default)]
+pub struct MemoryClipboard {
+    item: Option<ClipboardItem>,
+}
+
+impl ClipboardBackend for MemoryClipboard {
+    fn write(&mut self, item: ClipboardItem) {
+        self.item = Some(item);
+    }
+
+    fn read(&mut self) -> Option<ClipboardItem> {
+        self.item.clone()
+    }
+}
+
+/// The contents copied with this service, most recent first.
+///
+/// Contents copied by other applications are added when they are pasted.
+pub struct ClipboardHistory {
+    entries: VecDeque<ClipboardContent>,
+    capacity: usize,
+}
+
+impl Global for ClipboardHistory {}
+
+impl ClipboardHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &ClipboardContent> {
+        self.entries.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Write the content to the backend, and add it to the history.
+    pub fn copy(&mut self, content: ClipboardContent, backend: &mut impl ClipboardBackend) {
+        backend.write(content.to_item());
+        self.push(content);
+    }
+
+    /// Read the content of the backend, adding it to the history when it is new.
+    pub fn paste(&mut self, backend: &mut impl ClipboardBackend) -> Option<ClipboardContent> {
+        let content = ClipboardContent::from_item(&backend.read()?);
+        if self.entries.front() != Some(&content) {
+            self.push(content.clone());
+        }
+        Some(content)
+    }
+
+    /// Copy an entry of the history again, moving it to the front.
+    pub fn restore(&mut self, ix: usize, backend: &mut impl ClipboardBackend) {
+        if let Some(content) = self.entries.get(ix).cloned() {
+            self.copy(content, backend);
+        }
+    }
+
+    fn push(&mut self, content: ClipboardContent) {
+        self.entries.retain(|entry| *entry != content);
+        self.entries.push_front(content);
+        self.entries.truncate(self.capacity);
+    }
+}
+
+/// Copy the content to the clipboard of the app, and add it to the history.
+pub fn copy(content: impl Into<ClipboardContent>, cx: &mut AppContext) {
+    let content = content.into();
+    cx.update_global::<ClipboardHistory, _>(|history, cx| history.copy(content, cx));
+}
+
+/// Read the clipboard of the app.
+pub fn paste(cx: &mut AppContext) -> Option<ClipboardContent> {
+    cx.update_global::<ClipboardHistory, _>(|history, cx| history.paste(cx))
+}
+
+pub fn history(cx: &AppContext) -> &ClipboardHistory {
+    cx.global::<ClipboardHistory>()
+}
+
+pub enum ClipboardEvent {
+    Copied(ClipboardContent),
+}
+
+/// A button that copies its content, showing a check mark for a moment once copied.
+pub struct Clipboard {
+    content: ClipboardContent,
+    label: Option<SharedString>,
+    copied: bool,
+    _reset_task: Task<()>,
+}
+
+impl Clipboard {
+    pub fn new(content: impl Into<ClipboardContent>) -> Self {
+        Self {
+            content: content.into(),
+            label: None,
+            copied: false,
+            _reset_task: Task::ready(()),
+        }
+    }
+
+    /// The text shown next to the button until the content is copied.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn set_content(
+        &mut self,
+        content: impl Into<ClipboardContent>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.content = content.into();
+        cx.notify();
+    }
+
+    fn copy(&mut self, cx: &mut ViewContext<Self>) {
+        copy(self.content.clone(), cx);
+        cx.emit(ClipboardEvent::Copied(self.content.clone()));
+
+        self.copied = true;
+        self._reset_task = cx.spawn(|this, mut cx| async move {
+            Timer::after(COPIED_FEEDBACK).await;
+            this.update(&mut cx, |this, cx| {
+                this.copied = false;
+                cx.notify();
+            })
+            .ok();
+        });
+        cx.notify();
+    }
+}
+
+impl EventEmitter<ClipboardEvent> for Clipboard {}
+
+impl Render for Clipboard {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let id = cx.view().entity_id().as_u64() as usize;
+        let (icon, color) = if self.copied {
+            ("icons/check.svg", cx.theme().primary)
+        } else {
+            ("icons/copy.svg", cx.theme().muted_foreground)
+        };
+        let label = if self.copied {
+            Some("Copied!".into())
+        } else {
+            self.label.clone()
+        };
+
+        div()
+            .id(ElementId::NamedInteger("clipboard".into(), id))
+            .flex()
+            .items_center()
+            .gap_1()
+            .px_1()
+            .rounded_md()
+            .text_xs()
+            .text_color(color)
+            .cursor_pointer()
+            .hover(|this| this.bg(cx.theme().list_hover))
+            .child(svg().path(icon).size(px(14.)).text_color(color))
+            .children(label)
+            .on_click(cx.listener(|this, _, cx| this.copy(cx)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_typed_content_round_trip() {
+        let contents = [
+            ClipboardContent::Text("plain".into()),
+            ClipboardContent::Html {
+                html: "<b>bold</b>".into(),
+                text: "bold".into(),
+            },
+            ClipboardContent::Json(json!({ "name": "Ada", "tags": [1, 2] })),
+        ];
+
+        let mut backend = MemoryClipboard::default();
+        let mut history = ClipboardHistory::new(10);
+        for content in contents {
+            history.copy(content.clone(), &mut backend);
+            assert_eq!(history.paste(&mut backend), Some(content));
+        }
+    }
+
+    #[test]
+    fn test_content_from_other_applications() {
+        let mut backend = MemoryClipboard::default();
+        backend.write(ClipboardItem::new("{\"a\": 1}".into()));
+
+        let mut history = ClipboardHistory::new(10);
+        assert_eq!(
+            history.paste(&mut backend),
+            Some(ClipboardContent::Text("{\"a\": 1}".into()))
+        );
+        assert_eq!(history.entries().count(), 1);
+    }
+
+    #[test]
+    fn test_history() {
+        let mut backend = MemoryClipboard::default();
+        let mut history = ClipboardHistory::new(3);
+        for text in ["a", "b", "c", "d"] {
+            history.copy(text.into(), &mut backend);
+        }
+        let texts = |history: &ClipboardHistory| {
+            history
+                .entries()
+                .map(|entry| entry.text())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(texts(&history), ["d", "c", "b"]);
+
+        // Copying an entry again moves it to the front.
+        history.copy("b".into(), &mut backend);
+        assert_eq!(texts(&history), ["b", "d", "c"]);
+
+        history.restore(2, &mut backend);
+        assert_eq!(texts(&history), ["c", "b", "d"]);
+        assert_eq!(history.paste(&mut backend), Some("c".into()));
+        assert_eq!(texts(&history), ["c", "b", "d"]);
+
+        history.clear();
+        assert_eq!(history.entries().count(), 0);
+    }
+}
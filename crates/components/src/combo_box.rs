@@ -0,0 +1,446 @@
+use std::{ops::Range, time::Duration};
+
+use gpui::{
+    actions, deferred, div, prelude::FluentBuilder as _, px, AppContext, EventEmitter, FocusHandle,
+    FocusableView, FontWeight, HighlightStyle, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Render, SharedString, Styled as _, StyledText, Subscription, Task, Timer,
+    View, ViewContext, VisualContext as _, WeakView,
+};
+use ti::{
+    input::{InputEvent, TextInput},
+    list::{List, ListDelegate, ListItem},
+    theme::ActiveTheme,
+    v_flex,
+};
+
+actions!(combo_box, [SelectPrev, SelectNext, Confirm, Cancel]);
+
+const CONTEXT: &str = "ComboBox";
+const ITEM_HEIGHT: f32 = 30.;
+const MAX_VISIBLE_ITEMS: usize = 8;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("enter", Confirm, Some(CONTEXT)),
+        KeyBinding::new("escape", Cancel, Some(CONTEXT)),
+    ]);
+}
+
+/// A suggestion of a [`ComboBox`].
+#[derive(Clone)]
+pub struct Suggestion {
+    pub label: SharedString,
+    pub detail: Option<SharedString>,
+    /// The byte ranges of `label` that match the query, rendered in bold.
+    pub match_ranges: Vec<Range<usize>>,
+}
+
+impl Suggestion {
+    /// A suggestion for `label` when it contains the query, ignoring ASCII case.
+    pub fn matching(label: impl Into<SharedString>, query: &str) -> Option<Self> {
+        let label = label.into();
+        let match_ranges = match_ranges(&label, query)?;
+        Some(Self {
+            label,
+            detail: None,
+            match_ranges,
+        })
+    }
+
+    pub fn detail(mut self, detail: impl Into<SharedString>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+}
+
+/// The byte ranges of every occurrence of `query` in `text`, ignoring case.
+///
+/// Returns `None` when the query does not occur, and no range for an empty query.
+pub fn match_ranges(text: &str, query: &str) -> Option<Vec<Range<usize>>> {
+    if query.is_empty() {
+        return Some(Vec::new());
+    }
+
+    // Lowercasing can change the length of a character, so keep the offset in `text` of the
+    // character each byte of the lowercased text comes from.
+    let mut lowercase = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len());
+    for (ix, c) in text.char_indices() {
+        for lower in c.to_lowercase() {
+            lowercase.push(lower);
+            offsets.extend(std::iter::repeat(ix).take(lower.len_utf8()));
+        }
+    }
+    let end_of = |lower_ix: usize| {
+        let ix = offsets[lower_ix];
+        ix + text[ix..].chars().next().map_or(0, char::len_utf8)
+    };
+
+    let ranges = lowercase
+        .match_indices(&query.to_lowercase())
+        .map(|(ix, matched)| offsets[ix]..end_of(ix + matched.len() - 1))
+        .collect::<Vec<_>>();
+    (!ranges.is_empty()).then_some(ranges)
+}
+
+/// Provides the suggestions of a [`ComboBox`].
+pub trait ComboBoxDelegate: 'static {
+    /// Find the suggestions for the query, this may run in the background.
+    fn search(&self, query: &str, cx: &mut AppContext) -> Task<Vec<Suggestion>>;
+}
+
+pub enum ComboBoxEvent {
+    /// A value was picked, or typed when [`ComboBox::must_match`] is off.
+    Confirm(SharedString),
+}
+
+struct SuggestionListDelegate<D: ComboBoxDelegate> {
+    combo_box: WeakView<ComboBox<D>>,
+    suggestions: Vec<Suggestion>,
+    selected_index: Option<usize>,
+}
+
+impl<D: ComboBoxDelegate> ListDelegate for SuggestionListDelegate<D> {
+    type Item = ListItem;
+
+    fn items_count(&self) -> usize {
+        self.suggestions.len()
+    }
+
+    fn confirmed_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(suggestion) = ix.and_then(|ix| self.suggestions.get(ix)).cloned() else {
+            return;
+        };
+        self.combo_box
+            .update(cx, |combo_box, cx| {
+                combo_box.confirm_value(suggestion.label, cx)
+            })
+            .ok();
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let suggestion = self.suggestions.get(ix)?;
+        let selected = self.selected_index == Some(ix);
+        let highlight = HighlightStyle {
+            font_weight: Some(FontWeight::BOLD),
+            color: Some(cx.theme().primary),
+            ..Default::default()
+        };
+        let label = StyledText::new(suggestion.label.clone()).with_highlights(
+            &cx.text_style(),
+            suggestion
+                .match_ranges
+                .iter()
+                .map(|range| (range.clone(), highlight)),
+        );
+
+        Some(
+            ListItem::new(ix)
+                .h(px(ITEM_HEIGHT))
+                .px_2()
+                .when(selected, |this| this.bg(cx.theme().list_active))
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .justify_between()
+                        .gap_2()
+                        .w_full()
+                        .child(div().text_sm().child(label))
+                        .when_some(suggestion.detail.clone(), |this, detail| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(detail),
+                            )
+                        }),
+                ),
+        )
+    }
+}
+
+/// A text input with a list of suggestions that follow the typed text.
+///
+/// The suggestions are searched with a [`ComboBoxDelegate`] once typing pauses for the debounce
+/// delay. The up and down keys move in the list, enter picks the selected suggestion and
+/// escape closes the list.
+pub struct ComboBox<D: ComboBoxDelegate> {
+    delegate: D,
+    input: View<TextInput>,
+    list: View<List<SuggestionListDelegate<D>>>,
+    open: bool,
+    must_match: bool,
+    debounce: Duration,
+    /// The last confirmed value, restored when a typed value must match.
+    value: SharedString,
+    search_task: Task<()>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl<D: ComboBoxDelegate> ComboBox<D> {
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(TextInput::new);
+        let combo_box = cx.view().downgrade();
+        let list = cx.new_view(|cx| {
+            List::new(
+                SuggestionListDelegate {
+                    combo_box,
+                    suggestions: Vec::new(),
+                    selected_index: None,
+                },
+                cx,
+            )
+            .no_query()
+        });
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+
+        Self {
+            delegate,
+            input,
+            list,
+            open: false,
+            must_match: false,
+            debounce: Duration::from_millis(150),
+            value: SharedString::default(),
+            search_task: Task::ready(()),
+            _subscriptions,
+        }
+    }
+
+    /// Only accept a value from the suggestions, otherwise any typed text is accepted.
+    pub fn must_match(mut self, must_match: bool) -> Self {
+        self.must_match = must_match;
+        self
+    }
+
+    /// Wait for typing to pause this long before searching, 150ms by default.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    pub fn value(&self) -> SharedString {
+        self.value.clone()
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                let query = text.to_string();
+                if query != self.value.as_ref() {
+                    self.search(query, cx);
+                }
+            }
+            InputEvent::PressEnter => self.confirm(&Confirm, cx),
+            InputEvent::Blur => {
+                // Keep the list open while a suggestion is clicked.
+                if !self.list.focus_handle(cx).contains_focused(cx) {
+                    self.close(cx);
+                }
+            }
+            InputEvent::Focus => {}
+        }
+    }
+
+    fn search(&mut self, query: String, cx: &mut ViewContext<Self>) {
+        let debounce = self.debounce;
+        self.search_task = cx.spawn(|this, mut cx| async move {
+            Timer::after(debounce).await;
+            let Ok(task) = this.update(&mut cx, |this, cx| this.delegate.search(&query, cx)) else {
+                return;
+            };
+            let suggestions = task.await;
+            this.update(&mut cx, |this, cx| {
+                this.open = !suggestions.is_empty();
+                this.list.update(cx, |list, cx| {
+                    let delegate = list.delegate_mut();
+                    delegate.selected_index = (!suggestions.is_empty()).then_some(0);
+                    delegate.suggestions = suggestions;
+                    cx.notify();
+                });
+                cx.notify();
+            })
+            .ok();
+        });
+    }
+
+    fn select(&mut self, delta: isize, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            let query = self.input.read(cx).text().to_string();
+            self.search(query, cx);
+            return;
+        }
+
+        self.list.update(cx, |list, cx| {
+            let delegate = list.delegate_mut();
+            let count = delegate.suggestions.len() as isize;
+            if count == 0 {
+                return;
+            }
+            let ix = delegate
+                .selected_index
+                .map_or(0, |ix| (ix as isize + delta).rem_euclid(count));
+            delegate.selected_index = Some(ix as usize);
+            cx.notify();
+        });
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.select(-1, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.select(1, cx);
+    }
+
+    fn confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        let selected = self.open.then(|| {
+            let delegate = self.list.read(cx).delegate();
+            delegate
+                .selected_index
+                .and_then(|ix| delegate.suggestions.get(ix))
+                .map(|suggestion| suggestion.label.clone())
+        });
+
+        match selected.flatten() {
+            Some(label) => self.confirm_value(label, cx),
+            None if !self.must_match => {
+                let text = self.input.read(cx).text();
+                self.confirm_value(text, cx);
+            }
+            None => self.cancel(&Cancel, cx),
+        }
+    }
+
+    fn confirm_value(&mut self, value: SharedString, cx: &mut ViewContext<Self>) {
+        self.value = value.clone();
+        self.input
+            .update(cx, |input, cx| input.set_text(value.clone(), cx));
+        self.close(cx);
+        cx.emit(ComboBoxEvent::Confirm(value));
+    }
+
+    fn cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        self.close(cx);
+        if self.must_match {
+            let value = self.value.clone();
+            self.input.update(cx, |input, cx| input.set_text(value, cx));
+        }
+    }
+
+    fn close(&mut self, cx: &mut ViewContext<Self>) {
+        self.search_task = Task::ready(());
+        self.open = false;
+        cx.notify();
+    }
+}
+
+impl<D: ComboBoxDelegate> EventEmitter<ComboBoxEvent> for ComboBox<D> {}
+
+impl<D: ComboBoxDelegate> FocusableView for ComboBox<D> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl<D: ComboBoxDelegate> Render for ComboBox<D> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let visible_items = self
+            .list
+            .read(cx)
+            .delegate()
+            .suggestions
+            .len()
+            .min(MAX_VISIBLE_ITEMS);
+
+        div()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .relative()
+            .w_full()
+            .child(self.input.clone())
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        v_flex()
+                            .absolute()
+                            .top_full()
+                            .left_0()
+                            .w_full()
+                            .mt_1()
+                            .py_1()
+                            .h(px(ITEM_HEIGHT * visible_items as f32) + px(8.))
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .bg(cx.theme().background)
+                            .shadow_md()
+                            .child(self.list.clone()),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_ranges() {
+        assert_eq!(match_ranges("Hello", ""), Some(Vec::new()));
+        assert_eq!(match_ranges("Hello", "xyz"), None);
+        assert_eq!(match_ranges("", "a"), None);
+
+        // Case is ignored, on either side.
+        assert_eq!(match_ranges("Hello", "hEL"), Some(vec![0..3]));
+        assert_eq!(
+            match_ranges("Hello hello HELLO", "hello"),
+            Some(vec![0..5, 6..11, 12..17])
+        );
+        assert_eq!(match_ranges("aaaa", "aa"), Some(vec![0..2, 2..4]));
+    }
+
+    #[test]
+    fn test_match_ranges_non_ascii() {
+        // "\u{c9}" and "\u{e9}" are both two bytes.
+        let text = "\u{c9}cole, \u{e9}cole";
+        let ranges = match_ranges(text, "\u{e9}cole").unwrap();
+        assert_eq!(ranges, vec![0..6, 8..14]);
+        assert_eq!(&text[ranges[0].clone()], "\u{c9}cole");
+        assert_eq!(&text[ranges[1].clone()], "\u{e9}cole");
+
+        // "\u{130}" lowercases to three bytes, the ranges stay in the original text.
+        let text = "\u{130}stanbul Istanbul";
+        assert_eq!(match_ranges(text, "stanbul"), Some(vec![2..9, 11..18]));
+        let ranges = match_ranges(text, "\u{130}").unwrap();
+        assert_eq!(ranges, vec![0..2]);
+        assert_eq!(&text[ranges[0].clone()], "\u{130}");
+
+        assert_eq!(
+            match_ranges("\u{4e2d}\u{6587}", "\u{6587}"),
+            Some(vec![3..6])
+        );
+    }
+}
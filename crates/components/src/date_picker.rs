@@ -0,0 +1,563 @@
+use chrono::{Datelike as _, Days, Local, Locale, Months, NaiveDate};
+use gpui::{
+    actions, deferred, div, prelude::FluentBuilder as _, svg, AppContext, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding, MouseButton,
+    ParentElement as _, Render, StatefulInteractiveElement as _, Styled as _, Subscription, View,
+    ViewContext, VisualContext as _,
+};
+use ti::{
+    button::Button,
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme,
+    v_flex, IconName, Sizable as _,
+};
+
+actions!(date_picker, [Cancel]);
+
+const CONTEXT: &str = "DatePicker";
+/// The separator of the two dates of a range, as typed or shown.
+const RANGE_SEPARATOR: &str = " - ";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("escape", Cancel, Some(CONTEXT))]);
+}
+
+/// The `chrono` date format of the current `ti` locale.
+fn date_format() -> &'static str {
+    date_format_for(&ti::locale().to_string())
+}
+
+fn date_format_for(locale: &str) -> &'static str {
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next().unwrap_or_default();
+    match language {
+        "en" if matches!(region, "" | "US") => "%m/%d/%Y",
+        "en" | "fr" | "es" | "it" | "pt" | "id" => "%d/%m/%Y",
+        "de" | "ru" | "pl" | "cs" | "fi" | "nb" | "tr" | "uk" => "%d.%m.%Y",
+        "zh" | "ja" | "ko" => "%Y/%m/%d",
+        _ => "%Y-%m-%d",
+    }
+}
+
+/// Whether the weeks of the current `ti` locale start on Sunday, otherwise on Monday.
+fn week_starts_on_sunday() -> bool {
+    let locale = ti::locale().to_string();
+    matches!(
+        locale.as_str(),
+        "en" | "en-US" | "en_US" | "ja" | "ko" | "zh-TW" | "pt-BR"
+    )
+}
+
+/// The `chrono` locale matching the current `ti` locale, for the names of months and weekdays.
+fn chrono_locale() -> Locale {
+    chrono_locale_for(&ti::locale().to_string())
+}
+
+fn chrono_locale_for(locale: &str) -> Locale {
+    let locale = locale.replace('-', "_");
+    let language = locale.split('_').next().unwrap_or_default();
+    // The region of a bare language, when it isn't the language itself.
+    let region = match language {
+        "en" => "US".to_string(),
+        "zh" => "CN".to_string(),
+        "ja" => "JP".to_string(),
+        "ko" => "KR".to_string(),
+        "cs" => "CZ".to_string(),
+        "sv" => "SE".to_string(),
+        "nb" => "NO".to_string(),
+        "uk" => "UA".to_string(),
+        _ => language.to_uppercase(),
+    };
+    [locale.clone(), format!("{}_{}", language, region)]
+        .iter()
+        .find_map(|name| Locale::try_from(name.as_str()).ok())
+        .unwrap_or(Locale::en_US)
+}
+
+/// The abbreviated names of the weekdays, from Monday or from Sunday.
+fn weekday_names(locale: Locale, sunday_first: bool) -> Vec<String> {
+    // A Monday.
+    let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap_or_default();
+    let mut names = (0..7)
+        .map(|ix| {
+            (monday + Days::new(ix))
+                .format_localized("%a", locale)
+                .to_string()
+        })
+        .collect::<Vec<_>>();
+    if sunday_first {
+        names.rotate_right(1);
+    }
+    names
+}
+
+/// Format a date for the `ti` locale.
+pub fn format_date(date: NaiveDate) -> String {
+    date.format(date_format()).to_string()
+}
+
+/// Parse a date written for the `ti` locale, or in the ISO `yyyy-mm-dd` format.
+///
+/// Years before 1000 are rejected, to not take a two-digit year like `24` for the year 24.
+pub fn parse_date(text: &str) -> Option<NaiveDate> {
+    parse_date_with(text, date_format())
+}
+
+fn parse_date_with(text: &str, format: &str) -> Option<NaiveDate> {
+    let text = text.trim();
+    NaiveDate::parse_from_str(text, format)
+        .or_else(|_| NaiveDate::parse_from_str(text, "%Y-%m-%d"))
+        .ok()
+        .filter(|date| date.year() >= 1000)
+}
+
+/// The date format of the locale for a placeholder, like `mm/dd/yyyy`.
+fn format_hint() -> String {
+    date_format()
+        .replace("%d", "dd")
+        .replace("%m", "mm")
+        .replace("%Y", "yyyy")
+}
+
+/// The selection of a [`DatePicker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateSelection {
+    Single(NaiveDate),
+    /// An inclusive range, the start is never after the end.
+    Range(NaiveDate, NaiveDate),
+}
+
+impl DateSelection {
+    fn range(a: NaiveDate, b: NaiveDate) -> Self {
+        Self::Range(a.min(b), a.max(b))
+    }
+
+    fn start(&self) -> NaiveDate {
+        match self {
+            Self::Single(date) | Self::Range(date, _) => *date,
+        }
+    }
+
+    fn contains(&self, date: NaiveDate) -> bool {
+        match self {
+            Self::Single(selected) => *selected == date,
+            Self::Range(start, end) => (*start..=*end).contains(&date),
+        }
+    }
+
+    fn is_end(&self, date: NaiveDate) -> bool {
+        match self {
+            Self::Single(selected) => *selected == date,
+            Self::Range(start, end) => *start == date || *end == date,
+        }
+    }
+
+    fn format(&self) -> String {
+        match self {
+            Self::Single(date) => format_date(*date),
+            Self::Range(start, end) => {
+                format!(
+                    "{}{}{}",
+                    format_date(*start),
+                    RANGE_SEPARATOR,
+                    format_date(*end)
+                )
+            }
+        }
+    }
+}
+
+pub enum DatePickerEvent {
+    /// The selection changed, by typing or picking in the calendar.
+    Change(Option<DateSelection>),
+}
+
+/// A date input with a calendar to pick a date, or a range of dates.
+///
+/// Dates are typed in the format of the `ti` locale, and checked once the input loses focus or
+/// enter is pressed.
+pub struct DatePicker {
+    input: View<TextInput>,
+    range: bool,
+    value: Option<DateSelection>,
+    /// The first date picked in the calendar, until the end of the range is picked.
+    range_start: Option<NaiveDate>,
+    /// The first day of the month shown in the calendar.
+    month: NaiveDate,
+    open: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl DatePicker {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).placeholder(format_hint()));
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+        let today = Local::now().date_naive();
+
+        Self {
+            input,
+            range: false,
+            value: None,
+            range_start: None,
+            month: today.with_day(1).unwrap_or(today),
+            open: false,
+            _subscriptions,
+        }
+    }
+
+    /// Select a range of dates, typed as two dates separated by ` - `.
+    pub fn range(mut self, cx: &mut ViewContext<Self>) -> Self {
+        self.range = true;
+        let placeholder = format!("{}{}{}", format_hint(), RANGE_SEPARATOR, format_hint());
+        self.input = cx.new_view(|cx| TextInput::new(cx).placeholder(placeholder));
+        self._subscriptions = vec![cx.subscribe(&self.input, Self::on_input_event)];
+        self
+    }
+
+    pub fn value(&self) -> Option<DateSelection> {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: Option<DateSelection>, cx: &mut ViewContext<Self>) {
+        self.value = value;
+        if let Some(value) = value {
+            let date = value.start();
+            self.month = date.with_day(1).unwrap_or(date);
+        }
+        self.sync_input(cx);
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.input
+            .update(cx, |input, cx| input.set_disabled(disabled, cx));
+        if disabled {
+            self.close(cx);
+        }
+    }
+
+    fn sync_input(&self, cx: &mut ViewContext<Self>) {
+        let text = self.value.map(|value| value.format()).unwrap_or_default();
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+    }
+
+    fn parse(&self, text: &str) -> Option<DateSelection> {
+        if self.range {
+            let (start, end) = text.split_once(RANGE_SEPARATOR)?;
+            Some(DateSelection::range(parse_date(start)?, parse_date(end)?))
+        } else {
+            parse_date(text).map(DateSelection::Single)
+        }
+    }
+
+    fn change(&mut self, value: Option<DateSelection>, cx: &mut ViewContext<Self>) {
+        if value != self.value {
+            self.set_value(value, cx);
+            cx.emit(DatePickerEvent::Change(value));
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::PressEnter | InputEvent::Blur => {
+                let text = input.read(cx).text();
+                if text.trim().is_empty() {
+                    self.change(None, cx);
+                } else if let Some(value) = self.parse(&text) {
+                    self.change(Some(value), cx);
+                } else {
+                    // Restore the last valid value.
+                    self.sync_input(cx);
+                }
+            }
+            InputEvent::Change(_) | InputEvent::Focus => {}
+        }
+    }
+
+    fn open(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = true;
+        self.range_start = None;
+        cx.notify();
+    }
+
+    fn close(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        cx.notify();
+    }
+
+    fn cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        self.close(cx);
+    }
+
+    fn move_month(&mut self, months: i32, cx: &mut ViewContext<Self>) {
+        let month = if months < 0 {
+            self.month
+                .checked_sub_months(Months::new(months.unsigned_abs()))
+        } else {
+            self.month.checked_add_months(Months::new(months as u32))
+        };
+        if let Some(month) = month {
+            self.month = month;
+            cx.notify();
+        }
+    }
+
+    fn pick(&mut self, date: NaiveDate, cx: &mut ViewContext<Self>) {
+        if !self.range {
+            self.change(Some(DateSelection::Single(date)), cx);
+            self.close(cx);
+            return;
+        }
+
+        match self.range_start.take() {
+            Some(start) => {
+                self.change(Some(DateSelection::range(start, date)), cx);
+                self.close(cx);
+            }
+            None => {
+                self.range_start = Some(date);
+                cx.notify();
+            }
+        }
+    }
+
+    fn render_header(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let nav =
+            |id: &'static str, label: &'static str, months: i32, cx: &mut ViewContext<Self>| {
+                Button::new(id, cx)
+                    .label(label)
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(move |this, _, cx| this.move_month(months, cx)))
+            };
+
+        h_flex()
+            .justify_between()
+            .items_center()
+            .child(
+                h_flex().child(nav("prev-year", "«", -12, cx)).child(
+                    Button::new("prev-month", cx)
+                        .icon(IconName::ChevronLeft)
+                        .ghost()
+                        .small()
+                        .on_click(cx.listener(|this, _, cx| this.move_month(-1, cx))),
+                ),
+            )
+            .child(
+                div().text_sm().child(
+                    self.month
+                        .format_localized("%B %Y", chrono_locale())
+                        .to_string(),
+                ),
+            )
+            .child(
+                h_flex()
+                    .child(
+                        Button::new("next-month", cx)
+                            .icon(IconName::ChevronRight)
+                            .ghost()
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.move_month(1, cx))),
+                    )
+                    .child(nav("next-year", "»", 12, cx)),
+            )
+    }
+
+    fn render_calendar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let sunday_first = week_starts_on_sunday();
+        let weekdays = weekday_names(chrono_locale(), sunday_first);
+
+        let offset = if sunday_first {
+            self.month.weekday().num_days_from_sunday()
+        } else {
+            self.month.weekday().num_days_from_monday()
+        };
+        let first = self.month - Days::new(offset as u64);
+        let today = Local::now().date_naive();
+        // While a range is being picked, show its start alone.
+        let selection = self.range_start.map(DateSelection::Single).or(self.value);
+
+        let day = |ix: usize, cx: &mut ViewContext<Self>| {
+            let date = first + Days::new(ix as u64);
+            let in_month = date.month() == self.month.month();
+            let is_end = selection.is_some_and(|s| s.is_end(date));
+            let in_range = selection.is_some_and(|s| s.contains(date));
+
+            div()
+                .id(ElementId::NamedInteger("day".into(), ix))
+                .flex()
+                .items_center()
+                .justify_center()
+                .size_8()
+                .rounded_md()
+                .text_sm()
+                .cursor_pointer()
+                .when(!in_month, |this| {
+                    this.text_color(cx.theme().muted_foreground)
+                })
+                .when(date == today, |this| {
+                    this.border_1().border_color(cx.theme().primary)
+                })
+                .map(|this| {
+                    if is_end {
+                        this.bg(cx.theme().primary)
+                            .text_color(cx.theme().background)
+                    } else if in_range {
+                        this.bg(cx.theme().list_active)
+                    } else {
+                        this.hover(|this| this.bg(cx.theme().list_hover))
+                    }
+                })
+                .child(date.day().to_string())
+                .on_click(cx.listener(move |this, _, cx| this.pick(date, cx)))
+        };
+
+        v_flex()
+            .gap_1()
+            .child(h_flex().children(weekdays.into_iter().map(|weekday| {
+                div()
+                    .flex()
+                    .justify_center()
+                    .w_8()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(weekday)
+            })))
+            .children((0..6).map(|week| h_flex().children((0..7).map(|d| day(week * 7 + d, cx)))))
+    }
+}
+
+impl EventEmitter<DatePickerEvent> for DatePicker {}
+
+impl FocusableView for DatePicker {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for DatePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let id = cx.view().entity_id().as_u64() as usize;
+
+        div()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::cancel))
+            .relative()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(div().flex_1().child(self.input.clone()))
+                    .child(
+                        div()
+                            .id(ElementId::NamedInteger("calendar".into(), id))
+                            .p_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|this| this.bg(cx.theme().list_hover))
+                            .child(
+                                svg()
+                                    .path("icons/calendar.svg")
+                                    .size_4()
+                                    .text_color(cx.theme().muted_foreground),
+                            )
+                            .on_click(cx.listener(|this, _, cx| this.open(cx))),
+                    ),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        v_flex()
+                            .id(ElementId::NamedInteger("calendar-popup".into(), id))
+                            .absolute()
+                            .top_full()
+                            .left_0()
+                            .mt_1()
+                            .p_2()
+                            .gap_2()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .bg(cx.theme().background)
+                            .shadow_md()
+                            .on_mouse_down_out(cx.listener(|this, _, cx| this.close(cx)))
+                            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                            .child(self.render_header(cx))
+                            .child(self.render_calendar(cx)),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_format_date() {
+        let day = date(2024, 3, 9);
+        let format = |locale| day.format(date_format_for(locale)).to_string();
+        assert_eq!(format("en"), "03/09/2024");
+        assert_eq!(format("en-GB"), "09/03/2024");
+        assert_eq!(format("de"), "09.03.2024");
+        assert_eq!(format("zh-CN"), "2024/03/09");
+        assert_eq!(format("sv"), "2024-03-09");
+    }
+
+    #[test]
+    fn test_parse_date() {
+        let day = date(2024, 3, 9);
+        assert_eq!(parse_date_with("03/09/2024", "%m/%d/%Y"), Some(day));
+        assert_eq!(parse_date_with(" 09.03.2024 ", "%d.%m.%Y"), Some(day));
+        // The ISO format is always accepted.
+        assert_eq!(parse_date_with("2024-03-09", "%d.%m.%Y"), Some(day));
+        assert_eq!(parse_date_with("2/30/2024", "%m/%d/%Y"), None);
+        assert_eq!(parse_date_with("", "%m/%d/%Y"), None);
+        // A two-digit year is not the year 24.
+        assert_eq!(parse_date_with("03/09/24", "%m/%d/%Y"), None);
+        assert_eq!(parse_date_with("0999-12-31", "%m/%d/%Y"), None);
+        assert_eq!(
+            parse_date_with("1000-01-01", "%m/%d/%Y"),
+            Some(date(1000, 1, 1))
+        );
+
+        for locale in ["en", "en-GB", "de", "zh-CN", "sv"] {
+            let format = date_format_for(locale);
+            let text = day.format(format).to_string();
+            assert_eq!(parse_date_with(&text, format), Some(day), "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_localized_names() {
+        assert_eq!(chrono_locale_for("en"), Locale::en_US);
+        assert_eq!(chrono_locale_for("en-GB"), Locale::en_GB);
+        assert_eq!(chrono_locale_for("zh-CN"), Locale::zh_CN);
+        assert_eq!(chrono_locale_for("fr"), Locale::fr_FR);
+        assert_eq!(chrono_locale_for("xx"), Locale::en_US);
+
+        let weekdays = weekday_names(Locale::en_US, false);
+        assert_eq!(weekdays.first().map(String::as_str), Some("Mon"));
+        assert_eq!(weekdays.last().map(String::as_str), Some("Sun"));
+        let weekdays = weekday_names(Locale::en_US, true);
+        assert_eq!(weekdays.first().map(String::as_str), Some("Sun"));
+        assert_ne!(weekday_names(Locale::zh_CN, false)[0], "Mon");
+
+        let month = date(2024, 3, 1)
+            .format_localized("%B", Locale::fr_FR)
+            .to_string();
+        assert_eq!(month, "mars");
+    }
+}
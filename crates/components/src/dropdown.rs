@@ -0,0 +1,464 @@
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, AppContext, DismissEvent, ElementId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Render, SharedString, Styled as _, Task, View, ViewContext,
+    VisualContext as _, WeakView,
+};
+use ti::{
+    button::Button,
+    h_flex,
+    list::{List, ListDelegate, ListItem},
+    popover::Popover,
+    theme::ActiveTheme,
+    v_flex, IconName, Sizable as _,
+};
+
+actions!(dropdown, [Cancel]);
+
+const CONTEXT: &str = "Dropdown";
+const ITEM_HEIGHT: f32 = 30.;
+const MAX_VISIBLE_ITEMS: usize = 8;
+/// The height of the search input of the list.
+const SEARCH_HEIGHT: f32 = 40.;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("escape", Cancel, Some(CONTEXT))]);
+}
+
+/// Provides the items of a [`Dropdown`].
+pub trait DropdownDelegate: 'static {
+    fn items_count(&self) -> usize;
+
+    fn label(&self, ix: usize) -> SharedString;
+
+    /// Whether the item is listed for the search query, by default when its label contains it
+    /// ignoring case.
+    fn matches(&self, ix: usize, query: &str) -> bool {
+        self.label(ix)
+            .to_lowercase()
+            .contains(&query.to_lowercase())
+    }
+}
+
+impl DropdownDelegate for Vec<SharedString> {
+    fn items_count(&self) -> usize {
+        self.len()
+    }
+
+    fn label(&self, ix: usize) -> SharedString {
+        self[ix].clone()
+    }
+}
+
+pub enum DropdownEvent {
+    /// The selected items changed, by their indices in the delegate.
+    Change(Vec<usize>),
+}
+
+struct DropdownListDelegate<D: DropdownDelegate> {
+    dropdown: WeakView<Dropdown<D>>,
+    /// The indices in the delegate of the listed items.
+    matches: Vec<usize>,
+    selected_index: Option<usize>,
+}
+
+impl<D: DropdownDelegate> ListDelegate for DropdownListDelegate<D> {
+    type Item = ListItem;
+
+    fn items_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        let query = query.trim();
+        if let Some(dropdown) = self.dropdown.upgrade() {
+            let delegate = &dropdown.read(cx).delegate;
+            self.matches = (0..delegate.items_count())
+                .filter(|ix| query.is_empty() || delegate.matches(*ix, query))
+                .collect();
+        }
+        self.selected_index = (!self.matches.is_empty()).then_some(0);
+        cx.notify();
+        Task::ready(())
+    }
+
+    fn confirmed_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+
+    fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        let Some(item_ix) = ix.and_then(|ix| self.matches.get(ix)).copied() else {
+            return;
+        };
+        self.selected_index = ix;
+        self.dropdown
+            .update(cx, |dropdown, cx| dropdown.toggle(item_ix, cx))
+            .ok();
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let item_ix = *self.matches.get(ix)?;
+        let dropdown = self.dropdown.upgrade()?;
+        let dropdown = dropdown.read(cx);
+        let label = dropdown.delegate.label(item_ix);
+        let checked = dropdown.selected.contains(&item_ix);
+
+        Some(
+            ListItem::new(ix)
+                .h(px(ITEM_HEIGHT))
+                .px_2()
+                .when(self.selected_index == Some(ix), |this| {
+                    this.bg(cx.theme().list_active)
+                })
+                .child(
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .justify_between()
+                        .gap_2()
+                        .child(div().text_sm().child(label))
+                        .when(checked, |this| this.child(IconName::Check)),
+                ),
+        )
+    }
+}
+
+/// The popup of a [`Dropdown`], dismissed with escape or once a value is picked.
+struct DropdownMenu<D: DropdownDelegate> {
+    list: View<List<DropdownListDelegate<D>>>,
+    searchable: bool,
+}
+
+impl<D: DropdownDelegate> DropdownMenu<D> {
+    fn cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        cx.emit(DismissEvent);
+    }
+}
+
+impl<D: DropdownDelegate> EventEmitter<DismissEvent> for DropdownMenu<D> {}
+
+impl<D: DropdownDelegate> FocusableView for DropdownMenu<D> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.list.focus_handle(cx)
+    }
+}
+
+impl<D: DropdownDelegate> Render for DropdownMenu<D> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // Sized for all the items so the popup does not jump while searching.
+        let visible_items = self
+            .list
+            .read(cx)
+            .delegate()
+            .dropdown
+            .upgrade()
+            .map_or(0, |dropdown| dropdown.read(cx).delegate.items_count())
+            .clamp(1, MAX_VISIBLE_ITEMS);
+        let search_height = if self.searchable { SEARCH_HEIGHT } else { 0. };
+
+        v_flex()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::cancel))
+            .min_w(px(200.))
+            .h(px(ITEM_HEIGHT * visible_items as f32 + search_height))
+            .child(self.list.clone())
+    }
+}
+
+/// A button showing the selected value, that opens a list of items to pick from.
+///
+/// The list is navigated with the keyboard, and can be searched. In multiple mode several items
+/// are selected, shown as chips under the button.
+pub struct Dropdown<D: DropdownDelegate> {
+    delegate: D,
+    menu: View<DropdownMenu<D>>,
+    /// The indices in the delegate of the selected items, in their order.
+    selected: Vec<usize>,
+    placeholder: SharedString,
+    cleanable: bool,
+    multiple: bool,
+}
+
+impl<D: DropdownDelegate> Dropdown<D> {
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let items_count = delegate.items_count();
+        Self {
+            delegate,
+            menu: Self::build_menu(items_count, false, cx),
+            selected: Vec::new(),
+            placeholder: "Select...".into(),
+            cleanable: false,
+            multiple: false,
+        }
+    }
+
+    fn build_menu(
+        items_count: usize,
+        searchable: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> View<DropdownMenu<D>> {
+        let dropdown = cx.view().downgrade();
+        cx.new_view(|cx| {
+            let list = cx.new_view(|cx| {
+                let list = List::new(
+                    DropdownListDelegate {
+                        dropdown,
+                        matches: (0..items_count).collect(),
+                        selected_index: None,
+                    },
+                    cx,
+                );
+                if searchable {
+                    list
+                } else {
+                    list.no_query()
+                }
+            });
+            DropdownMenu { list, searchable }
+        })
+    }
+
+    /// Show a search input above the items.
+    pub fn searchable(mut self, cx: &mut ViewContext<Self>) -> Self {
+        self.menu = Self::build_menu(self.delegate.items_count(), true, cx);
+        self
+    }
+
+    /// The text of the button when nothing is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Show a button to clear the selection.
+    pub fn cleanable(mut self) -> Self {
+        self.cleanable = true;
+        self
+    }
+
+    /// Select several items, the popup stays open while picking them.
+    pub fn multiple(mut self) -> Self {
+        self.multiple = true;
+        self
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    pub fn selected_indices(&self) -> &[usize] {
+        &self.selected
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected.first().copied()
+    }
+
+    pub fn set_selected_indices(&mut self, indices: Vec<usize>, cx: &mut ViewContext<Self>) {
+        let items_count = self.delegate.items_count();
+        self.selected = indices.into_iter().filter(|ix| *ix < items_count).collect();
+        if !self.multiple {
+            self.selected.truncate(1);
+        }
+        cx.notify();
+    }
+
+    fn toggle(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.multiple {
+            match self.selected.iter().position(|selected| *selected == ix) {
+                Some(pos) => {
+                    self.selected.remove(pos);
+                }
+                None => self.selected.push(ix),
+            }
+        } else {
+            self.selected = vec![ix];
+            self.menu.update(cx, |_, cx| cx.emit(DismissEvent));
+        }
+        self.changed(cx);
+    }
+
+    fn remove(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.selected.retain(|selected| *selected != ix);
+        self.changed(cx);
+    }
+
+    fn clear(&mut self, cx: &mut ViewContext<Self>) {
+        self.selected.clear();
+        self.changed(cx);
+    }
+
+    fn changed(&mut self, cx: &mut ViewContext<Self>) {
+        cx.emit(DropdownEvent::Change(self.selected.clone()));
+        cx.notify();
+    }
+
+    fn trigger_label(&self) -> SharedString {
+        match self.selected.as_slice() {
+            [] => self.placeholder.clone(),
+            [ix] => self.delegate.label(*ix),
+            selected if self.multiple => format!("{} selected", selected.len()).into(),
+            [ix, ..] => self.delegate.label(*ix),
+        }
+    }
+
+    fn render_chip(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_1()
+            .pl_2()
+            .items_center()
+            .rounded_md()
+            .text_xs()
+            .bg(cx.theme().list_active)
+            .child(self.delegate.label(ix))
+            .child(
+                Button::new(("remove", ix), cx)
+                    .icon(IconName::Close)
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(move |this, _, cx| this.remove(ix, cx))),
+            )
+    }
+}
+
+impl<D: DropdownDelegate> EventEmitter<DropdownEvent> for Dropdown<D> {}
+
+impl<D: DropdownDelegate> FocusableView for Dropdown<D> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.menu.focus_handle(cx)
+    }
+}
+
+impl<D: DropdownDelegate> Render for Dropdown<D> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let id = cx.view().entity_id().as_u64() as usize;
+        let menu = self.menu.clone();
+
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .items_center()
+                    .child(
+                        Popover::new(ElementId::NamedInteger("dropdown".into(), id))
+                            .trigger(
+                                Button::new(ElementId::NamedInteger("trigger".into(), id), cx)
+                                    .icon(IconName::ChevronDown)
+                                    .label(self.trigger_label()),
+                            )
+                            .content(move |_| menu.clone()),
+                    )
+                    .when(self.cleanable && !self.selected.is_empty(), |this| {
+                        this.child(
+                            Button::new(ElementId::NamedInteger("clear".into(), id), cx)
+                                .icon(IconName::Close)
+                                .ghost()
+                                .small()
+                                .on_click(cx.listener(|this, _, cx| this.clear(cx))),
+                        )
+                    }),
+            )
+            .when(self.multiple && !self.selected.is_empty(), |this| {
+                this.child(
+                    h_flex()
+                        .id(ElementId::NamedInteger("chips".into(), id))
+                        .flex_wrap()
+                        .gap_1()
+                        .children(
+                            self.selected
+                                .clone()
+                                .into_iter()
+                                .map(|ix| self.render_chip(ix, cx)),
+                        ),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{TestAppContext, VisualTestContext};
+    use ti::theme::Theme;
+
+    use super::*;
+
+    fn build_dropdown(
+        multiple: bool,
+        cx: &mut TestAppContext,
+    ) -> (View<Dropdown<Vec<SharedString>>>, &mut VisualTestContext) {
+        cx.update(|cx| Theme::init(cx));
+        cx.add_window_view(|cx| {
+            let items = vec!["Apple".into(), "Banana".into(), "Cherry".into()];
+            let dropdown = Dropdown::new(items, cx).placeholder("Fruit");
+            if multiple {
+                dropdown.multiple()
+            } else {
+                dropdown
+            }
+        })
+    }
+
+    #[gpui::test]
+    fn test_trigger_label(cx: &mut TestAppContext) {
+        let (single, single_cx) = build_dropdown(false, cx);
+        single.update(single_cx, |this, cx| {
+            assert_eq!(this.trigger_label(), "Fruit");
+            this.set_selected_indices(vec![1], cx);
+            assert_eq!(this.trigger_label(), "Banana");
+
+            // A single dropdown keeps the first index, and ignores the ones out of range.
+            this.set_selected_indices(vec![5, 2, 0], cx);
+            assert_eq!(this.selected_indices(), [2]);
+            assert_eq!(this.trigger_label(), "Cherry");
+        });
+
+        let (multiple, cx) = build_dropdown(true, cx);
+        multiple.update(cx, |this, cx| {
+            assert_eq!(this.trigger_label(), "Fruit");
+            this.set_selected_indices(vec![2], cx);
+            assert_eq!(this.trigger_label(), "Cherry");
+            this.set_selected_indices(vec![2, 0], cx);
+            assert_eq!(this.trigger_label(), "2 selected");
+        });
+    }
+
+    #[gpui::test]
+    fn test_toggle_and_clear(cx: &mut TestAppContext) {
+        let (multiple, multiple_cx) = build_dropdown(true, cx);
+        multiple.update(multiple_cx, |this, cx| {
+            // Items are kept in the order they were picked.
+            this.toggle(2, cx);
+            this.toggle(0, cx);
+            assert_eq!(this.selected_indices(), [2, 0]);
+            assert_eq!(this.selected_index(), Some(2));
+
+            this.toggle(2, cx);
+            assert_eq!(this.selected_indices(), [0]);
+
+            this.toggle(1, cx);
+            this.remove(0, cx);
+            assert_eq!(this.selected_indices(), [1]);
+
+            this.toggle(0, cx);
+            this.clear(cx);
+            assert!(this.selected_indices().is_empty());
+            assert_eq!(this.selected_index(), None);
+        });
+
+        let (single, cx) = build_dropdown(false, cx);
+        single.update(cx, |this, cx| {
+            // A single dropdown replaces its item.
+            this.toggle(2, cx);
+            this.toggle(0, cx);
+            assert_eq!(this.selected_indices(), [0]);
+            this.toggle(0, cx);
+            assert_eq!(this.selected_indices(), [0]);
+        });
+    }
+}
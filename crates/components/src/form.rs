@@ -0,0 +1,391 @@
+use std::{collections::HashMap, str::FromStr};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, AppContext, FocusHandle, FocusableView, IntoElement,
+    ParentElement as _, Render, SharedString, Styled as _, Subscription, View, ViewContext,
+};
+use regex::Regex;
+use ti::{
+    h_flex,
+    input::{InputEvent, TextInput},
+    label::Label,
+    theme::ActiveTheme,
+    v_flex,
+};
+
+/// Checks the text of a field, returning the error message to show under it.
+pub type Rule = Box<dyn Fn(&str) -> Result<(), SharedString>>;
+
+pub fn min_length(len: usize) -> Rule {
+    Box::new(move |text| {
+        if text.chars().count() < len {
+            return Err(format!("Must be at least {} characters.", len).into());
+        }
+        Ok(())
+    })
+}
+
+pub fn max_length(len: usize) -> Rule {
+    Box::new(move |text| {
+        if text.chars().count() > len {
+            return Err(format!("Must be at most {} characters.", len).into());
+        }
+        Ok(())
+    })
+}
+
+pub fn pattern(regex: Regex, message: impl Into<SharedString>) -> Rule {
+    let message = message.into();
+    Box::new(move |text| {
+        if !regex.is_match(text) {
+            return Err(message.clone());
+        }
+        Ok(())
+    })
+}
+
+pub fn email() -> Rule {
+    pattern(
+        Regex::new(r"^[^@\s]+@[^@\s]+\.[^@\s]+$").unwrap(),
+        "Must be an email address.",
+    )
+}
+
+/// A labeled text field of a [`Form`].
+pub struct FormField {
+    name: SharedString,
+    label: SharedString,
+    description: Option<SharedString>,
+    input: View<TextInput>,
+    required: bool,
+    rules: Vec<Rule>,
+    error: Option<SharedString>,
+}
+
+impl FormField {
+    pub fn new(
+        name: impl Into<SharedString>,
+        label: impl Into<SharedString>,
+        input: View<TextInput>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            description: None,
+            input,
+            required: false,
+            rules: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Reject an empty field. The rules of an optional field only apply to a non-empty text.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    fn validate(&self, text: &str) -> Result<(), SharedString> {
+        validate(text, self.required, &self.rules)
+    }
+}
+
+/// Check a text against the rules, an empty text only fails when it is required.
+fn validate(text: &str, required: bool, rules: &[Rule]) -> Result<(), SharedString> {
+    if text.trim().is_empty() {
+        return match required {
+            true => Err("This field is required.".into()),
+            false => Ok(()),
+        };
+    }
+
+    rules.iter().try_for_each(|rule| rule(text))
+}
+
+/// An error of a field, when building the value of a form.
+pub struct FieldError {
+    pub field: SharedString,
+    pub message: SharedString,
+}
+
+/// The text of each field of a form, by name.
+pub struct FormValues(HashMap<SharedString, SharedString>);
+
+impl FormValues {
+    /// The text of the field, empty for an unknown field.
+    pub fn get(&self, field: &str) -> SharedString {
+        self.0.get(field).cloned().unwrap_or_default()
+    }
+
+    /// The text of the field, `None` when it is empty.
+    pub fn optional(&self, field: &str) -> Option<SharedString> {
+        Some(self.get(field)).filter(|text| !text.trim().is_empty())
+    }
+
+    pub fn parse<T: FromStr>(&self, field: &str) -> Result<T, FieldError> {
+        self.get(field).trim().parse().map_err(|_| FieldError {
+            field: field.to_string().into(),
+            message: "Invalid value.".into(),
+        })
+    }
+}
+
+/// Labeled text fields with validation, submitted as a value of type `T`.
+///
+/// The form only renders its fields, the buttons are left to the view that owns it and calls
+/// [`Form::submit`].
+pub struct Form<T> {
+    focus_handle: FocusHandle,
+    fields: Vec<FormField>,
+    build: Box<dyn Fn(&FormValues) -> Result<T, FieldError>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl<T: 'static> Form<T> {
+    /// Create a form, `build` makes the submitted value once every field is valid.
+    pub fn new(
+        build: impl Fn(&FormValues) -> Result<T, FieldError> + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            fields: Vec::new(),
+            build: Box::new(build),
+            _subscriptions: Vec::new(),
+        }
+    }
+
+    pub fn field(mut self, field: FormField, cx: &mut ViewContext<Self>) -> Self {
+        // Clear the error once the text is fixed.
+        let subscription = cx.subscribe(&field.input, |this, input, event: &InputEvent, cx| {
+            if let InputEvent::Change(_) = event {
+                let text = input.read(cx).text();
+                if let Some(field) = this
+                    .fields
+                    .iter_mut()
+                    .find(|field| field.input == input && field.error.is_some())
+                {
+                    field.error = field.validate(&text).err();
+                    cx.notify();
+                }
+            }
+        });
+
+        self._subscriptions.push(subscription);
+        self.fields.push(field);
+        self
+    }
+
+    /// Validate every field, and build the value when they are all valid.
+    ///
+    /// Otherwise the errors are shown under the fields and the first invalid field is focused.
+    pub fn submit(&mut self, cx: &mut ViewContext<Self>) -> Option<T> {
+        let mut values = HashMap::new();
+        for field in &mut self.fields {
+            let text = field.input.read(cx).text();
+            field.error = field.validate(&text).err();
+            values.insert(field.name.clone(), text);
+        }
+
+        if self.fields.iter().all(|field| field.error.is_none()) {
+            match (self.build)(&FormValues(values)) {
+                Ok(value) => return Some(value),
+                Err(err) => {
+                    if let Some(field) = self.fields.iter_mut().find(|f| f.name == err.field) {
+                        field.error = Some(err.message);
+                    }
+                }
+            }
+        }
+
+        if let Some(field) = self.fields.iter().find(|field| field.error.is_some()) {
+            let focus_handle = field.input.focus_handle(cx);
+            cx.focus(&focus_handle);
+        }
+        cx.notify();
+        None
+    }
+
+    /// Clear the errors and the text of every field.
+    pub fn reset(&mut self, cx: &mut ViewContext<Self>) {
+        for field in &mut self.fields {
+            field.error = None;
+            field.input.update(cx, |input, cx| input.set_text("", cx));
+        }
+        cx.notify();
+    }
+}
+
+impl<T: 'static> FocusableView for Form<T> {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        match self.fields.first() {
+            Some(field) => field.input.focus_handle(cx),
+            None => self.focus_handle.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Render for Form<T> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().gap_4().children(self.fields.iter().map(|field| {
+            v_flex()
+                .gap_1()
+                .child(
+                    h_flex()
+                        .gap_1()
+                        .child(Label::new(field.label.clone()).text_sm())
+                        .map(|this| {
+                            if field.required {
+                                this.child(div().text_color(cx.theme().destructive).child("*"))
+                            } else {
+                                this.child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child("(optional)"),
+                                )
+                            }
+                        }),
+                )
+                .child(field.input.clone())
+                .map(|this| match (&field.error, &field.description) {
+                    (Some(error), _) => this.child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().destructive)
+                            .child(error.clone()),
+                    ),
+                    (None, Some(description)) => this.child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(description.clone()),
+                    ),
+                    (None, None) => this,
+                })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{TestAppContext, VisualContext as _};
+    use ti::theme::Theme;
+
+    use super::*;
+
+    #[test]
+    fn test_rules() {
+        assert!(min_length(3)("abc").is_ok());
+        assert!(min_length(3)("ab").is_err());
+        // Lengths count characters, not bytes.
+        assert!(min_length(3)("\u{e9}t\u{e9}").is_ok());
+        assert!(max_length(3)("\u{e9}t\u{e9}").is_ok());
+        assert!(max_length(3)("abcd").is_err());
+
+        let digits = pattern(Regex::new(r"^\d+$").unwrap(), "Digits only.");
+        assert!(digits("123").is_ok());
+        assert_eq!(digits("12a").unwrap_err(), "Digits only.");
+
+        assert!(email()("ada@example.com").is_ok());
+        assert!(email()("ada@example").is_err());
+        assert!(email()("ada example@x.com").is_err());
+    }
+
+    #[test]
+    fn test_validate_empty_text() {
+        let rules = vec![min_length(3)];
+        assert_eq!(
+            validate("", true, &rules).unwrap_err(),
+            "This field is required."
+        );
+        assert!(validate("  ", true, &rules).is_err());
+        // The rules of an optional field don't apply to an empty text.
+        assert!(validate("", false, &rules).is_ok());
+        assert!(validate("  ", false, &rules).is_ok());
+        assert!(validate("ab", false, &rules).is_err());
+        assert!(validate("abc", true, &rules).is_ok());
+    }
+
+    #[test]
+    fn test_form_values() {
+        let values = FormValues(HashMap::from([
+            ("name".into(), "Ada".into()),
+            ("age".into(), " 36 ".into()),
+            ("bio".into(), "  ".into()),
+        ]));
+
+        assert_eq!(values.get("name"), "Ada");
+        assert_eq!(values.get("unknown"), "");
+        assert_eq!(values.optional("name").as_deref(), Some("Ada"));
+        assert_eq!(values.optional("bio"), None);
+        assert_eq!(values.optional("unknown"), None);
+
+        assert_eq!(values.parse::<u32>("age").ok(), Some(36));
+        let err = values.parse::<u32>("name").err().unwrap();
+        assert_eq!(err.field, "name");
+        assert_eq!(err.message, "Invalid value.");
+    }
+
+    #[gpui::test]
+    fn test_submit_focuses_first_invalid_field(cx: &mut TestAppContext) {
+        cx.update(|cx| Theme::init(cx));
+        let (form, cx) = cx.add_window_view(|cx| {
+            let name = cx.new_view(TextInput::new);
+            let email_input = cx.new_view(TextInput::new);
+            let age = cx.new_view(TextInput::new);
+            Form::new(
+                |values| Ok((values.get("name"), values.parse::<u32>("age")?)),
+                cx,
+            )
+            .field(FormField::new("name", "Name", name).required(), cx)
+            .field(
+                FormField::new("email", "Email", email_input)
+                    .required()
+                    .rule(email()),
+                cx,
+            )
+            .field(FormField::new("age", "Age", age), cx)
+        });
+
+        form.update(cx, |form, cx| {
+            let inputs = form
+                .fields
+                .iter()
+                .map(|field| field.input.clone())
+                .collect::<Vec<_>>();
+            let set_text = |ix: usize, text: &'static str, cx: &mut ViewContext<Form<_>>| {
+                inputs[ix].update(cx, |input, cx| input.set_text(text, cx));
+            };
+
+            // The name is valid, the email isn't.
+            set_text(0, "Ada", cx);
+            set_text(1, "ada@", cx);
+            assert!(form.submit(cx).is_none());
+            assert!(form.fields[0].error.is_none());
+            assert!(form.fields[1].error.is_some());
+            assert!(inputs[1].focus_handle(cx).is_focused(cx));
+
+            // Every field is valid, but the value can't be built from the age.
+            set_text(1, "ada@example.com", cx);
+            set_text(2, "old", cx);
+            assert!(form.submit(cx).is_none());
+            assert!(form.fields[1].error.is_none());
+            assert_eq!(form.fields[2].error.as_deref(), Some("Invalid value."));
+            assert!(inputs[2].focus_handle(cx).is_focused(cx));
+
+            set_text(2, "36", cx);
+            assert_eq!(form.submit(cx), Some((SharedString::from("Ada"), 36)));
+        });
+    }
+}
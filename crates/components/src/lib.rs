@@ -0,0 +1,32 @@
+//! Components missing from `ti`, built on its primitives.
+
+use gpui::AppContext;
+
+pub mod clipboard;
+pub mod combo_box;
+pub mod date_picker;
+pub mod dropdown;
+pub mod form;
+pub mod number_input;
+pub mod otp_input;
+pub mod progress;
+pub mod radio;
+pub mod skeleton;
+pub mod slider;
+pub mod spinner;
+pub mod table;
+pub mod text_area;
+pub mod time_picker;
+
+pub fn init(cx: &mut AppContext) {
+    clipboard::init(cx);
+    combo_box::init(cx);
+    date_picker::init(cx);
+    dropdown::init(cx);
+    number_input::init(cx);
+    radio::init(cx);
+    slider::init(cx);
+    table::init(cx);
+    text_area::init(cx);
+    time_picker::init(cx);
+}
@@ -0,0 +1,345 @@
+use gpui::{
+    actions, px, AppContext, ElementId, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Render, ScrollWheelEvent,
+    Styled as _, Subscription, View, ViewContext, VisualContext as _,
+};
+use ti::{
+    button::Button,
+    h_flex,
+    input::{InputEvent, TextInput},
+    IconName, Sizable as _,
+};
+
+actions!(number_input, [Increment, Decrement]);
+
+const CONTEXT: &str = "NumberInput";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+    ]);
+}
+
+pub enum NumberInputEvent {
+    /// The value changed, by typing or stepping, none once the input is cleared.
+    Change(Option<f64>),
+}
+
+/// The grouping and decimal separators of the current `ti` locale.
+fn separators() -> (char, char) {
+    separators_for(&ti::locale().to_string())
+}
+
+fn separators_for(locale: &str) -> (char, char) {
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    match language {
+        "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" => ('.', ','),
+        "fr" | "ru" | "pl" | "cs" | "sv" | "fi" | "nb" | "uk" => ('\u{a0}', ','),
+        _ => (',', '.'),
+    }
+}
+
+/// Format a number with the separators of the locale, `grouping` adds thousands separators.
+pub fn format_number(value: f64, precision: usize, grouping: bool) -> String {
+    let (group, decimal) = separators();
+    format_with(value, precision, grouping.then_some(group), decimal)
+}
+
+fn format_with(value: f64, precision: usize, group: Option<char>, decimal: char) -> String {
+    let text = format!("{:.*}", precision, value.abs());
+    let (int_part, frac_part) = match text.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (text.as_str(), None),
+    };
+
+    let mut formatted = String::new();
+    if value < 0. && text.chars().any(|c| c != '0' && c != '.') {
+        formatted.push('-');
+    }
+    for (ix, c) in int_part.chars().enumerate() {
+        if let Some(group) = group.filter(|_| ix > 0 && (int_part.len() - ix) % 3 == 0) {
+            formatted.push(group);
+        }
+        formatted.push(c);
+    }
+    if let Some(frac_part) = frac_part {
+        formatted.push(decimal);
+        formatted.push_str(frac_part);
+    }
+    formatted
+}
+
+/// Parse a number written with the separators of the locale.
+pub fn parse_number(text: &str) -> Option<f64> {
+    let (group, decimal) = separators();
+    parse_with(text, group, decimal)
+}
+
+fn parse_with(text: &str, group: char, decimal: char) -> Option<f64> {
+    let text = text
+        .trim()
+        .chars()
+        .filter(|c| *c != group && *c != ' ')
+        .map(|c| if c == decimal { '.' } else { c })
+        .collect::<String>();
+    text.parse().ok()
+}
+
+/// A numeric input with increment and decrement buttons.
+///
+/// The value can also be stepped with the up and down keys, or the scroll wheel while focused.
+/// It is formatted for the `ti` locale once the input loses focus.
+pub struct NumberInput {
+    input: View<TextInput>,
+    value: Option<f64>,
+    min: f64,
+    max: f64,
+    step: f64,
+    /// The number of decimals, 0 for integers.
+    precision: usize,
+    grouping: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NumberInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx).validate(|text| {
+                let (group, decimal) = separators();
+                // A typed space stands in for a non-breaking space, like in `parse_number`.
+                text.chars().all(|c| {
+                    c.is_ascii_digit() || c == '-' || c == ' ' || c == group || c == decimal
+                })
+            })
+        });
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+
+        Self {
+            input,
+            value: None,
+            min: f64::MIN,
+            max: f64::MAX,
+            step: 1.,
+            precision: 0,
+            grouping: true,
+            _subscriptions,
+        }
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Only accept integers, the default.
+    pub fn integer(mut self) -> Self {
+        self.precision = 0;
+        self
+    }
+
+    /// Accept decimals, rounded to the given number of digits.
+    pub fn decimal(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Whether to show thousands separators, enabled by default.
+    pub fn grouping(mut self, grouping: bool) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: f64, cx: &mut ViewContext<Self>) {
+        let value = self.clamp(value);
+        self.value = Some(value);
+        let text = format_number(value, self.precision, self.grouping);
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.input
+            .update(cx, |input, cx| input.set_disabled(disabled, cx));
+        cx.notify();
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.precision as i32);
+        ((value * factor).round() / factor).clamp(self.min, self.max)
+    }
+
+    fn step_by(&mut self, steps: f64, cx: &mut ViewContext<Self>) {
+        let value = self.value.unwrap_or(self.min.max(0.).min(self.max));
+        let next = self.clamp(value + self.step * steps);
+        if Some(next) != self.value {
+            self.set_value(next, cx);
+            cx.emit(NumberInputEvent::Change(Some(next)));
+        }
+    }
+
+    fn increment(&mut self, _: &Increment, cx: &mut ViewContext<Self>) {
+        self.step_by(1., cx);
+    }
+
+    fn decrement(&mut self, _: &Decrement, cx: &mut ViewContext<Self>) {
+        self.step_by(-1., cx);
+    }
+
+    fn on_scroll_wheel(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        if !self.input.focus_handle(cx).is_focused(cx) {
+            return;
+        }
+
+        let delta = event.delta.pixel_delta(px(20.)).y;
+        if delta != px(0.) {
+            cx.stop_propagation();
+            self.step_by(if delta > px(0.) { 1. } else { -1. }, cx);
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                // Keep the last value while the text is not a number yet, like a lone `-`.
+                let value = if text.trim().is_empty() {
+                    None
+                } else {
+                    match parse_number(text) {
+                        Some(value) => Some(self.clamp(value)),
+                        None => return,
+                    }
+                };
+                if value != self.value {
+                    self.value = value;
+                    cx.emit(NumberInputEvent::Change(value));
+                }
+            }
+            // Show the value without grouping while editing, and formatted otherwise.
+            InputEvent::Focus => {
+                if let Some(value) = self.value {
+                    let text = format_number(value, self.precision, false);
+                    input.update(cx, |input, cx| input.set_text(text, cx));
+                }
+            }
+            InputEvent::Blur => match self.value {
+                Some(value) => self.set_value(value, cx),
+                None => input.update(cx, |input, cx| input.set_text("", cx)),
+            },
+            InputEvent::PressEnter => {}
+        }
+    }
+}
+
+impl EventEmitter<NumberInputEvent> for NumberInput {}
+
+impl FocusableView for NumberInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for NumberInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let id = cx.view().entity_id().as_u64() as usize;
+
+        h_flex()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::increment))
+            .on_action(cx.listener(Self::decrement))
+            .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+            .gap_1()
+            .child(
+                Button::new(ElementId::NamedInteger("decrement".into(), id), cx)
+                    .icon(IconName::Minus)
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(|this, _, cx| this.step_by(-1., cx))),
+            )
+            .child(self.input.clone())
+            .child(
+                Button::new(ElementId::NamedInteger("increment".into(), id), cx)
+                    .icon(IconName::Plus)
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(|this, _, cx| this.step_by(1., cx))),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        let (group, decimal) = separators_for("en");
+        assert_eq!(
+            format_with(1234567.891, 2, Some(group), decimal),
+            "1,234,567.89"
+        );
+        assert_eq!(format_with(1234567.891, 2, None, decimal), "1234567.89");
+        assert_eq!(format_with(123., 0, Some(group), decimal), "123");
+        assert_eq!(format_with(1000., 0, Some(group), decimal), "1,000");
+        assert_eq!(format_with(-1234.5, 1, Some(group), decimal), "-1,234.5");
+        assert_eq!(format_with(0.125, 2, Some(group), decimal), "0.13");
+        // Negative values that round to zero don't keep their sign.
+        assert_eq!(format_with(-0.001, 2, Some(group), decimal), "0.00");
+        assert_eq!(format_with(-0.4, 0, Some(group), decimal), "0");
+        assert_eq!(format_with(-0., 0, Some(group), decimal), "0");
+    }
+
+    #[test]
+    fn test_separators() {
+        assert_eq!(separators_for("en"), (',', '.'));
+        assert_eq!(separators_for("zh-CN"), (',', '.'));
+        assert_eq!(separators_for("de"), ('.', ','));
+        assert_eq!(separators_for("pt_BR"), ('.', ','));
+        assert_eq!(separators_for("fr"), ('\u{a0}', ','));
+        assert_eq!(separators_for("ru-RU"), ('\u{a0}', ','));
+
+        for locale in ["en", "de", "fr"] {
+            let (group, decimal) = separators_for(locale);
+            let text = format_with(-1234567.25, 2, Some(group), decimal);
+            assert_eq!(
+                parse_with(&text, group, decimal),
+                Some(-1234567.25),
+                "{locale}"
+            );
+        }
+        assert_eq!(format_with(1234.5, 1, Some('.'), ','), "1.234,5");
+        assert_eq!(format_with(1234.5, 1, Some('\u{a0}'), ','), "1\u{a0}234,5");
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_with(" 1,234.5 ", ',', '.'), Some(1234.5));
+        assert_eq!(parse_with("-42", ',', '.'), Some(-42.));
+        assert_eq!(parse_with("1.234,5", '.', ','), Some(1234.5));
+        // A typed space is accepted for a non-breaking space.
+        assert_eq!(parse_with("1\u{a0}234,5", '\u{a0}', ','), Some(1234.5));
+        assert_eq!(parse_with("1 234,5", '\u{a0}', ','), Some(1234.5));
+        assert_eq!(parse_with("", ',', '.'), None);
+        assert_eq!(parse_with("-", ',', '.'), None);
+        assert_eq!(parse_with("1.2.3", ',', '.'), None);
+    }
+}
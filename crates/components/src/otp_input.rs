@@ -0,0 +1,361 @@
+use gpui::{
+    div, px, AppContext, EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement,
+    ParentElement as _, Pixels, Render, SharedString, Styled as _, Subscription, View, ViewContext,
+    VisualContext as _,
+};
+use ti::{
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme,
+    Sizable, Size,
+};
+
+pub enum OtpEvent {
+    /// The value changed, it may not be complete yet.
+    Change(SharedString),
+    /// Every cell is filled.
+    Complete(SharedString),
+}
+
+/// A one-time-password input, with a [`TextInput`] per digit.
+///
+/// Typing a digit fills its cell and moves to the next one, clearing a cell moves back to the
+/// previous one, and pasting a code into any cell spreads its digits over the following cells.
+pub struct OtpInput {
+    focus_handle: FocusHandle,
+    length: usize,
+    groups: usize,
+    digits: Vec<Option<char>>,
+    masked: bool,
+    disabled: bool,
+    size: Size,
+    /// The cells, created on first use so that the builder options apply to them.
+    cells: Vec<View<TextInput>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl OtpInput {
+    pub fn new(length: usize, cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![cx.on_focus(&focus_handle, Self::focus_cell)];
+
+        Self {
+            focus_handle,
+            length,
+            groups: 2,
+            digits: vec![None; length],
+            masked: false,
+            disabled: false,
+            size: Size::Medium,
+            cells: Vec::new(),
+            _subscriptions,
+        }
+    }
+
+    /// Set the initial value, only its first `length` digits are kept.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.digits = self.sanitize(&value.into());
+        self
+    }
+
+    /// Split the cells into the given number of groups, 2 by default.
+    pub fn groups(mut self, groups: usize) -> Self {
+        self.groups = groups.max(1);
+        self
+    }
+
+    pub fn masked(mut self, masked: bool) -> Self {
+        self.masked = masked;
+        self
+    }
+
+    pub fn set_masked(&mut self, masked: bool, cx: &mut ViewContext<Self>) {
+        self.masked = masked;
+        for cell in &self.cells {
+            cell.update(cx, |input, cx| input.set_masked(masked, cx));
+        }
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        for cell in &self.cells {
+            cell.update(cx, |input, cx| input.set_disabled(disabled, cx));
+        }
+        cx.notify();
+    }
+
+    pub fn set_value(&mut self, value: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.digits = self.sanitize(&value.into());
+        self.sync_cells(0, cx);
+        cx.notify();
+    }
+
+    /// The digits of the filled cells.
+    pub fn value(&self) -> SharedString {
+        self.digits.iter().flatten().collect::<String>().into()
+    }
+
+    fn sanitize(&self, value: &str) -> Vec<Option<char>> {
+        let mut digits = value
+            .chars()
+            .filter(char::is_ascii_digit)
+            .take(self.length)
+            .map(Some)
+            .collect::<Vec<_>>();
+        digits.resize(self.length, None);
+        digits
+    }
+
+    fn ensure_cells(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.cells.is_empty() {
+            return;
+        }
+
+        let (size, masked, disabled) = (self.size, self.masked, self.disabled);
+        self.cells = (0..self.length)
+            .map(|_| {
+                cx.new_view(|cx| {
+                    let mut input = TextInput::new(cx).with_size(size).validate(|text| {
+                        text.chars()
+                            .all(|c| c.is_ascii_digit() || c.is_whitespace() || c == '-')
+                    });
+                    input.set_masked(masked, cx);
+                    input.set_disabled(disabled, cx);
+                    input
+                })
+            })
+            .collect();
+        self._subscriptions
+            .extend(self.cells.iter().enumerate().map(|(ix, cell)| {
+                cx.subscribe(cell, move |this, _, event: &InputEvent, cx| {
+                    if let InputEvent::Change(text) = event {
+                        this.on_cell_change(ix, text, cx);
+                    }
+                })
+            }));
+        self.sync_cells(0, cx);
+    }
+
+    /// Write the digits from the cell `from` onwards back to the cells.
+    fn sync_cells(&mut self, from: usize, cx: &mut ViewContext<Self>) {
+        for (cell, digit) in self.cells.iter().zip(&self.digits).skip(from) {
+            let text = digit.map(String::from).unwrap_or_default();
+            cell.update(cx, |input, cx| input.set_text(text, cx));
+        }
+    }
+
+    fn on_cell_change(&mut self, ix: usize, text: &str, cx: &mut ViewContext<Self>) {
+        let Some((digits, focus)) = merge_digits(&self.digits, ix, text) else {
+            // Put back a cell left without a digit, unless it is the echo of our own text.
+            let shown = self.digits[ix].map(String::from).unwrap_or_default();
+            if text != shown {
+                self.sync_cells(ix, cx);
+            }
+            return;
+        };
+
+        self.digits = digits;
+        self.sync_cells(ix, cx);
+        if let Some(cell) = self.cells.get(focus) {
+            let handle = cell.focus_handle(cx);
+            cx.focus(&handle);
+        }
+
+        let value = self.value();
+        cx.emit(OtpEvent::Change(value.clone()));
+        if self.digits.iter().all(Option::is_some) {
+            cx.emit(OtpEvent::Complete(value));
+        }
+        cx.notify();
+    }
+
+    /// Move the focus from the input to its first empty cell.
+    fn focus_cell(&mut self, cx: &mut ViewContext<Self>) {
+        self.ensure_cells(cx);
+        let ix = self
+            .digits
+            .iter()
+            .position(Option::is_none)
+            .unwrap_or(self.length.saturating_sub(1));
+        if let Some(cell) = self.cells.get(ix) {
+            let handle = cell.focus_handle(cx);
+            cx.focus(&handle);
+        }
+    }
+
+    fn cell_width(&self) -> Pixels {
+        match self.size {
+            Size::XSmall => px(24.),
+            Size::Small => px(30.),
+            Size::Large => px(48.),
+            Size::Size(size) => size,
+            _ => px(40.),
+        }
+    }
+}
+
+/// Merge the text of the cell `ix` into the digits, returning the new digits and the cell to
+/// focus, or `None` when the digits don't change.
+///
+/// A digit typed into a filled cell replaces its digit, a pasted code fills the following cells,
+/// and clearing a cell moves back to the previous one.
+fn merge_digits(
+    digits: &[Option<char>],
+    ix: usize,
+    text: &str,
+) -> Option<(Vec<Option<char>>, usize)> {
+    let old = digits[ix];
+    let typed = text
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<Vec<_>>();
+    let last = digits.len().saturating_sub(1);
+    let mut digits = digits.to_vec();
+
+    let focus = match typed.as_slice() {
+        // Only separators, or the digit already in the cell.
+        [] if old.is_none() => return None,
+        [digit] if old == Some(*digit) => return None,
+        [] => {
+            digits[ix] = None;
+            ix.saturating_sub(1)
+        }
+        [a, b] if old == Some(*a) || old == Some(*b) => {
+            digits[ix] = Some(if old == Some(*a) { *b } else { *a });
+            ix + 1
+        }
+        _ => {
+            let filled = typed.len().min(digits.len() - ix);
+            for (digit, c) in digits[ix..].iter_mut().zip(typed.iter().copied()) {
+                *digit = Some(c);
+            }
+            ix + filled
+        }
+    };
+    Some((digits, focus.min(last)))
+}
+
+impl Sizable for OtpInput {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<OtpEvent> for OtpInput {}
+
+impl FocusableView for OtpInput {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for OtpInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.ensure_cells(cx);
+
+        let cell_width = self.cell_width();
+        let group_len = self.length.div_ceil(self.groups).max(1);
+        let mut groups = Vec::new();
+        let mut cells = self.cells.iter().peekable();
+        while cells.peek().is_some() {
+            groups.push(
+                h_flex().gap_1().children(
+                    cells
+                        .by_ref()
+                        .take(group_len)
+                        .map(|cell| div().flex_none().w(cell_width).child(cell.clone())),
+                ),
+            );
+        }
+
+        h_flex()
+            .id("otp-input")
+            .track_focus(&self.focus_handle)
+            .items_center()
+            .gap_2()
+            .children(groups.into_iter().enumerate().flat_map(|(ix, group)| {
+                let separator = (ix > 0).then(|| {
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child("-")
+                        .into_any_element()
+                });
+                separator.into_iter().chain(Some(group.into_any_element()))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Vec<Option<char>> {
+        text.chars().map(|c| c.to_digit(10).and(Some(c))).collect()
+    }
+
+    #[test]
+    fn test_merge_digits_typing() {
+        assert_eq!(
+            merge_digits(&digits("______"), 0, "1"),
+            Some((digits("1_____"), 1))
+        );
+        assert_eq!(
+            merge_digits(&digits("12345_"), 5, "6"),
+            Some((digits("123456"), 5))
+        );
+        // The echo of the digit already in the cell.
+        assert_eq!(merge_digits(&digits("1_____"), 0, "1"), None);
+    }
+
+    #[test]
+    fn test_merge_digits_overwrite() {
+        // The new digit is kept, on either side of the old one.
+        assert_eq!(
+            merge_digits(&digits("123___"), 1, "29"),
+            Some((digits("193___"), 2))
+        );
+        assert_eq!(
+            merge_digits(&digits("123___"), 1, "92"),
+            Some((digits("193___"), 2))
+        );
+    }
+
+    #[test]
+    fn test_merge_digits_paste() {
+        assert_eq!(
+            merge_digits(&digits("______"), 2, "12 34-56"),
+            Some((digits("__1234"), 5))
+        );
+        assert_eq!(
+            merge_digits(&digits("999999"), 0, "12 34-56"),
+            Some((digits("123456"), 5))
+        );
+        assert_eq!(
+            merge_digits(&digits("______"), 0, "123"),
+            Some((digits("123___"), 3))
+        );
+    }
+
+    #[test]
+    fn test_merge_digits_clear() {
+        assert_eq!(
+            merge_digits(&digits("123___"), 2, ""),
+            Some((digits("12____"), 1))
+        );
+        assert_eq!(
+            merge_digits(&digits("1_____"), 0, ""),
+            Some((digits("______"), 0))
+        );
+        // Clearing an empty cell changes nothing.
+        assert_eq!(merge_digits(&digits("______"), 0, ""), None);
+    }
+
+    #[test]
+    fn test_merge_digits_separators() {
+        assert_eq!(merge_digits(&digits("______"), 0, "- "), None);
+        // A separator typed after the digit of a cell leaves it as it was.
+        assert_eq!(merge_digits(&digits("1_____"), 0, "1-"), None);
+    }
+}
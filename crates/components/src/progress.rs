@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use gpui::{
+    div, ease_in_out, prelude::FluentBuilder as _, relative, Animation, AnimationExt as _,
+    IntoElement, ParentElement as _, RenderOnce, Styled as _, WindowContext,
+};
+use ti::theme::ActiveTheme;
+
+/// The width of the moving bar of an indeterminate progress, relative to the track.
+const INDETERMINATE_WIDTH: f32 = 0.3;
+
+/// A horizontal progress bar.
+///
+/// With a value from 0 to 100 the bar is determinate, otherwise a bar moves across the
+/// track until the value is known.
+#[derive(IntoElement)]
+pub struct Progress {
+    value: Option<f32>,
+}
+
+impl Progress {
+    pub fn new() -> Self {
+        Self { value: None }
+    }
+
+    /// The percentage done, clamped from 0 to 100.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(value.clamp(0., 100.));
+        self
+    }
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for Progress {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = cx.theme().primary;
+
+        div()
+            .relative()
+            .w_full()
+            .h_2()
+            .rounded_full()
+            .overflow_hidden()
+            .bg(color.opacity(0.2))
+            .map(|this| match self.value {
+                Some(value) => this.child(
+                    div()
+                        .h_full()
+                        .w(relative(value / 100.))
+                        .rounded_full()
+                        .bg(color),
+                ),
+                None => this.child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .h_full()
+                        .w(relative(INDETERMINATE_WIDTH))
+                        .rounded_full()
+                        .bg(color)
+                        .with_animation(
+                            "progress-indeterminate",
+                            Animation::new(Duration::from_millis(1500))
+                                .repeat()
+                                .with_easing(ease_in_out),
+                            |this, delta| {
+                                // From just before the start to just after the end of the track.
+                                let left = delta * (1. + INDETERMINATE_WIDTH) - INDETERMINATE_WIDTH;
+                                this.left(relative(left))
+                            },
+                        ),
+                ),
+            })
+    }
+}
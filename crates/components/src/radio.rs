@@ -0,0 +1,265 @@
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, AppContext, Axis, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    ViewContext,
+};
+use ti::{h_flex, label::Label, switch::LabelSide, theme::ActiveTheme, v_flex, FocusableCycle};
+
+actions!(radio, [SelectPrev, SelectNext, Confirm]);
+
+const CONTEXT: &str = "RadioGroup";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("left", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("space", Confirm, Some(CONTEXT)),
+    ]);
+}
+
+/// An option of a [`RadioGroup`].
+pub struct Radio {
+    label: SharedString,
+    description: Option<SharedString>,
+    disabled: bool,
+}
+
+impl Radio {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            disabled: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+pub enum RadioGroupEvent {
+    /// An option was selected, by its index.
+    Change(usize),
+}
+
+struct RadioOption {
+    radio: Radio,
+    focus_handle: FocusHandle,
+}
+
+/// A set of options of which one is selected.
+///
+/// The group is a single tab stop of a [`FocusableCycle`], focusing the selected option. The arrow
+/// keys move the selection between the enabled options, wrapping around.
+pub struct RadioGroup {
+    focus_handle: FocusHandle,
+    options: Vec<RadioOption>,
+    selected_index: Option<usize>,
+    axis: Axis,
+    label_side: LabelSide,
+    disabled: bool,
+}
+
+impl RadioGroup {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            options: Vec::new(),
+            selected_index: None,
+            axis: Axis::Vertical,
+            label_side: LabelSide::Right,
+            disabled: false,
+        }
+    }
+
+    pub fn option(mut self, radio: Radio, cx: &mut ViewContext<Self>) -> Self {
+        self.options.push(RadioOption {
+            radio,
+            focus_handle: cx.focus_handle(),
+        });
+        self
+    }
+
+    /// Lay the options out in a row, they are in a column by default.
+    pub fn horizontal(mut self) -> Self {
+        self.axis = Axis::Horizontal;
+        self
+    }
+
+    /// The side of the labels, on the right of the radio buttons by default.
+    pub fn label_side(mut self, label_side: LabelSide) -> Self {
+        self.label_side = label_side;
+        self
+    }
+
+    pub fn selected(mut self, ix: usize) -> Self {
+        self.selected_index = Some(ix);
+        self
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        cx.notify();
+    }
+
+    fn is_enabled(&self, ix: usize) -> bool {
+        !self.disabled && self.options.get(ix).is_some_and(|o| !o.radio.disabled)
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.is_enabled(ix) {
+            return;
+        }
+
+        cx.focus(&self.options[ix].focus_handle);
+        if self.selected_index != Some(ix) {
+            self.selected_index = Some(ix);
+            cx.emit(RadioGroupEvent::Change(ix));
+        }
+        cx.notify();
+    }
+
+    /// Move the focus to the next enabled option, and select it.
+    fn move_selection(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        self.cycle_focus(forward, cx);
+        if let Some(ix) = self
+            .options
+            .iter()
+            .position(|option| option.focus_handle.is_focused(cx))
+        {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.move_selection(false, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.move_selection(true, cx);
+    }
+
+    fn confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self
+            .options
+            .iter()
+            .position(|option| option.focus_handle.is_focused(cx))
+        {
+            self.select(ix, cx);
+        }
+    }
+
+    fn render_option(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let option = &self.options[ix];
+        let enabled = self.is_enabled(ix);
+        let checked = self.selected_index == Some(ix);
+        let focused = option.focus_handle.is_focused(cx);
+        let color = if enabled {
+            cx.theme().primary
+        } else {
+            cx.theme().muted_foreground
+        };
+
+        let indicator = div()
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .size_4()
+            .rounded_full()
+            .border_1()
+            .border_color(color)
+            .when(checked, |this| {
+                this.child(div().size_2().rounded_full().bg(color))
+            });
+        let label = v_flex()
+            .child(
+                Label::new(option.radio.label.clone())
+                    .text_sm()
+                    .when(!enabled, |this| {
+                        this.text_color(cx.theme().muted_foreground)
+                    }),
+            )
+            .when_some(option.radio.description.clone(), |this, description| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(description),
+                )
+            });
+
+        h_flex()
+            .id(ElementId::NamedInteger("radio".into(), ix))
+            .track_focus(&option.focus_handle)
+            .gap_2()
+            .px_1()
+            .rounded_md()
+            .items_start()
+            .when(focused, |this| this.bg(cx.theme().list_hover))
+            .map(|this| match self.label_side {
+                LabelSide::Left => this.child(label).child(indicator),
+                LabelSide::Right => this.child(indicator).child(label),
+            })
+            .when(enabled, |this| {
+                this.cursor_pointer()
+                    .on_click(cx.listener(move |this, _, cx| this.select(ix, cx)))
+            })
+    }
+}
+
+impl FocusableCycle for RadioGroup {
+    fn cycle_focus_handles(&self, _: &mut ViewContext<Self>) -> Vec<FocusHandle> {
+        (0..self.options.len())
+            .filter(|ix| self.is_enabled(*ix))
+            .map(|ix| self.options[ix].focus_handle.clone())
+            .collect()
+    }
+}
+
+impl EventEmitter<RadioGroupEvent> for RadioGroup {}
+
+impl FocusableView for RadioGroup {
+    // The selected option, or the first enabled one, so the group is entered on its selection.
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.selected_index
+            .filter(|ix| self.is_enabled(*ix))
+            .or_else(|| (0..self.options.len()).find(|ix| self.is_enabled(*ix)))
+            .map(|ix| self.options[ix].focus_handle.clone())
+            .unwrap_or_else(|| self.focus_handle.clone())
+    }
+}
+
+impl Render for RadioGroup {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        match self.axis {
+            Axis::Horizontal => h_flex().gap_4(),
+            Axis::Vertical => v_flex().gap_2(),
+        }
+        .key_context(CONTEXT)
+        .track_focus(&self.focus_handle)
+        .on_action(cx.listener(Self::select_prev))
+        .on_action(cx.listener(Self::select_next))
+        .on_action(cx.listener(Self::confirm))
+        .children((0..self.options.len()).map(|ix| self.render_option(ix, cx)))
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use gpui::{
+    div, pulsating_between, relative, Animation, AnimationExt as _, Div, IntoElement,
+    ParentElement as _, RenderOnce, StyleRefinement, Styled, WindowContext,
+};
+use ti::{h_flex, theme::ActiveTheme, v_flex};
+
+/// The widths of the lines of the placeholders, relative to their row, so they look like text.
+const LINE_WIDTHS: [f32; 4] = [0.8, 0.6, 0.7, 0.45];
+
+/// A pulsing block standing for content that is loading.
+///
+/// It is a text line by default, size it like the content it stands for.
+#[derive(IntoElement)]
+pub struct Skeleton {
+    base: Div,
+}
+
+impl Skeleton {
+    pub fn new() -> Self {
+        Self {
+            base: div().w_full().h_4().rounded_md(),
+        }
+    }
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Styled for Skeleton {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Skeleton {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = cx.theme().muted_foreground;
+
+        self.base.with_animation(
+            "skeleton",
+            Animation::new(Duration::from_secs(2))
+                .repeat()
+                .with_easing(pulsating_between(0.5, 1.)),
+            move |this, delta| this.bg(color.opacity(0.15 * delta)),
+        )
+    }
+}
+
+/// A placeholder with the shape of a `ListItem` row, the `ix` varies the length of its line.
+pub fn list_item(ix: usize) -> Div {
+    h_flex()
+        .w_full()
+        .gap_2()
+        .px_3()
+        .py_1()
+        .child(Skeleton::new().size_4().flex_none().rounded_full())
+        .child(Skeleton::new().w(relative(LINE_WIDTHS[ix % LINE_WIDTHS.len()])))
+}
+
+/// A placeholder with the shape of a bordered section, a title and `lines` of content.
+pub fn section(lines: usize, cx: &WindowContext) -> Div {
+    v_flex()
+        .w_full()
+        .gap_3()
+        .p_4()
+        .rounded_lg()
+        .border_1()
+        .border_color(cx.theme().border)
+        .child(Skeleton::new().h_5().w(relative(0.3)))
+        .children(
+            (0..lines).map(|ix| Skeleton::new().w(relative(LINE_WIDTHS[ix % LINE_WIDTHS.len()]))),
+        )
+}
@@ -0,0 +1,634 @@
+use gpui::{
+    actions, canvas, div, prelude::FluentBuilder as _, px, relative, AppContext, Axis, Bounds,
+    DragMoveEvent, ElementId, EntityId, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseUpEvent,
+    ParentElement as _, Pixels, Point, Render, StatefulInteractiveElement as _, Styled as _,
+    Subscription, View, ViewContext, VisualContext as _,
+};
+use ti::{
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme,
+    v_flex,
+};
+
+use crate::number_input::{format_number, parse_number};
+
+actions!(
+    slider,
+    [
+        Increment,
+        Decrement,
+        IncrementPage,
+        DecrementPage,
+        Home,
+        End
+    ]
+);
+
+const CONTEXT: &str = "Slider";
+const THUMB_SIZE: Pixels = Pixels(16.);
+const RAIL_SIZE: Pixels = Pixels(4.);
+/// The number of steps moved by page up and page down.
+const PAGE_STEPS: f64 = 10.;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("right", Increment, Some(CONTEXT)),
+        KeyBinding::new("up", Increment, Some(CONTEXT)),
+        KeyBinding::new("left", Decrement, Some(CONTEXT)),
+        KeyBinding::new("down", Decrement, Some(CONTEXT)),
+        KeyBinding::new("pageup", IncrementPage, Some(CONTEXT)),
+        KeyBinding::new("pagedown", DecrementPage, Some(CONTEXT)),
+        KeyBinding::new("home", Home, Some(CONTEXT)),
+        KeyBinding::new("end", End, Some(CONTEXT)),
+    ]);
+}
+
+/// The value of a [`Slider`], a single value or a range with a thumb at each end.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderValue {
+    Single(f64),
+    Range(f64, f64),
+}
+
+impl SliderValue {
+    pub fn start(&self) -> f64 {
+        match self {
+            Self::Single(value) | Self::Range(value, _) => *value,
+        }
+    }
+
+    pub fn end(&self) -> f64 {
+        match self {
+            Self::Single(value) | Self::Range(_, value) => *value,
+        }
+    }
+
+    fn thumbs(&self) -> Vec<f64> {
+        match self {
+            Self::Single(value) => vec![*value],
+            Self::Range(start, end) => vec![*start, *end],
+        }
+    }
+
+    /// Move a thumb, a range thumb stops at the other end of the range.
+    fn with_thumb(self, thumb: usize, value: f64) -> Self {
+        match self {
+            Self::Single(_) => Self::Single(value),
+            Self::Range(_, end) if thumb == 0 => Self::Range(value.min(end), end),
+            Self::Range(start, _) => Self::Range(start, value.max(start)),
+        }
+    }
+}
+
+/// Round a value to the nearest step from `min`, within `min..=max`.
+fn snap(value: f64, min: f64, max: f64, step: f64) -> f64 {
+    let steps = ((value - min) / step).round();
+    (min + steps * step).clamp(min, max)
+}
+
+/// The number of decimals of a step, to format the values.
+fn precision(step: f64) -> usize {
+    (0..6)
+        .find(|precision| {
+            let scaled = step * 10f64.powi(*precision as i32);
+            (scaled - scaled.round()).abs() < 1e-9
+        })
+        .unwrap_or(6)
+}
+
+pub enum SliderEvent {
+    /// The value changed, emitted on every move while dragging.
+    Change(SliderValue),
+    /// The value was committed, when a drag ends or the value is set with the keyboard or the
+    /// linked input.
+    Release(SliderValue),
+}
+
+#[derive(Clone, Render)]
+struct DragThumb {
+    slider: EntityId,
+    thumb: usize,
+}
+
+/// A horizontal or vertical slider, with a single thumb or a thumb at each end of a range.
+///
+/// Values snap to the step. The focused thumb moves with the arrow, page, home and end keys, and
+/// a linked text input can show the value for exact entry.
+pub struct Slider {
+    focus_handle: FocusHandle,
+    axis: Axis,
+    min: f64,
+    max: f64,
+    step: f64,
+    value: SliderValue,
+    /// The interval of the tick marks, none by default.
+    ticks: Option<f64>,
+    /// The thumb moved by the keyboard, the last one clicked or dragged.
+    active_thumb: usize,
+    dragging: bool,
+    disabled: bool,
+    /// The bounds of the track, from the last paint.
+    bounds: Bounds<Pixels>,
+    inputs: Vec<View<TextInput>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Slider {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            axis: Axis::Horizontal,
+            min: 0.,
+            max: 100.,
+            step: 1.,
+            value: SliderValue::Single(0.),
+            ticks: None,
+            active_thumb: 0,
+            dragging: false,
+            disabled: false,
+            bounds: Bounds::default(),
+            inputs: Vec::new(),
+            _subscriptions: Vec::new(),
+        }
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.axis = Axis::Vertical;
+        self
+    }
+
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self.value = self.snap_value(self.value);
+        self
+    }
+
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self.value = self.snap_value(self.value);
+        self
+    }
+
+    /// Set the step of the values, 1 by default. A step that is not positive and finite is ignored.
+    pub fn step(mut self, step: f64) -> Self {
+        if !(step.is_finite() && step > 0.) {
+            return self;
+        }
+        self.step = step;
+        self.value = self.snap_value(self.value);
+        self
+    }
+
+    pub fn default_value(mut self, value: f64) -> Self {
+        self.value = self.snap_value(SliderValue::Single(value));
+        self
+    }
+
+    /// Select a range, with a thumb at each end.
+    pub fn range(mut self, start: f64, end: f64) -> Self {
+        self.value = self.snap_value(SliderValue::Range(start.min(end), start.max(end)));
+        self
+    }
+
+    /// Show a tick mark every `interval`, from the minimum.
+    pub fn ticks(mut self, interval: f64) -> Self {
+        self.ticks = Some(interval);
+        self
+    }
+
+    /// Show a text input for each thumb, to enter an exact value.
+    ///
+    /// Call it after [`Slider::range`], to get an input per end of the range.
+    pub fn with_input(mut self, cx: &mut ViewContext<Self>) -> Self {
+        self.inputs = (0..self.value.thumbs().len())
+            .map(|_| cx.new_view(TextInput::new))
+            .collect();
+        self._subscriptions = self
+            .inputs
+            .iter()
+            .enumerate()
+            .map(|(thumb, input)| {
+                cx.subscribe(input, move |this, input, event: &InputEvent, cx| {
+                    if let InputEvent::PressEnter | InputEvent::Blur = event {
+                        let text = input.read(cx).text();
+                        match parse_number(&text) {
+                            Some(value) => this.commit_thumb(thumb, value, cx),
+                            None => this.sync_inputs(cx),
+                        }
+                    }
+                })
+            })
+            .collect();
+        self.sync_inputs(cx);
+        self
+    }
+
+    pub fn value(&self) -> SliderValue {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: SliderValue, cx: &mut ViewContext<Self>) {
+        self.value = self.snap_value(value);
+        self.sync_inputs(cx);
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        for input in &self.inputs {
+            input.update(cx, |input, cx| input.set_disabled(disabled, cx));
+        }
+        cx.notify();
+    }
+
+    fn precision(&self) -> usize {
+        precision(self.step)
+    }
+
+    fn snap(&self, value: f64) -> f64 {
+        snap(value, self.min, self.max, self.step)
+    }
+
+    fn snap_value(&self, value: SliderValue) -> SliderValue {
+        match value {
+            SliderValue::Single(value) => SliderValue::Single(self.snap(value)),
+            SliderValue::Range(start, end) => {
+                let (start, end) = (self.snap(start), self.snap(end));
+                SliderValue::Range(start.min(end), end.max(start))
+            }
+        }
+    }
+
+    fn with_thumb(&self, thumb: usize, value: f64) -> SliderValue {
+        self.value.with_thumb(thumb, self.snap(value))
+    }
+
+    /// Move a thumb, emitting a change when the value moved.
+    fn move_thumb(&mut self, thumb: usize, value: f64, cx: &mut ViewContext<Self>) -> bool {
+        let value = self.with_thumb(thumb, value);
+        self.active_thumb = thumb;
+        if value == self.value {
+            return false;
+        }
+
+        self.value = value;
+        self.sync_inputs(cx);
+        cx.emit(SliderEvent::Change(value));
+        cx.notify();
+        true
+    }
+
+    fn commit_thumb(&mut self, thumb: usize, value: f64, cx: &mut ViewContext<Self>) {
+        if self.move_thumb(thumb, value, cx) {
+            cx.emit(SliderEvent::Release(self.value));
+        } else {
+            // Show the snapped value in place of the entered one.
+            self.sync_inputs(cx);
+        }
+    }
+
+    fn sync_inputs(&self, cx: &mut ViewContext<Self>) {
+        let precision = self.precision();
+        for (input, value) in self.inputs.iter().zip(self.value.thumbs()) {
+            let text = format_number(value, precision, false);
+            input.update(cx, |input, cx| input.set_text(text, cx));
+        }
+    }
+
+    /// The position of a value along the track, from 0 at the minimum to 1 at the maximum.
+    fn fraction(&self, value: f64) -> f32 {
+        if self.max <= self.min {
+            return 0.;
+        }
+        ((value - self.min) / (self.max - self.min)) as f32
+    }
+
+    fn value_at(&self, position: Point<Pixels>, bounds: Bounds<Pixels>) -> f64 {
+        let fraction = match self.axis {
+            Axis::Horizontal => (position.x - bounds.left()) / bounds.size.width,
+            Axis::Vertical => (bounds.bottom() - position.y) / bounds.size.height,
+        };
+        let fraction = if fraction.is_finite() {
+            fraction.clamp(0., 1.)
+        } else {
+            0.
+        };
+        self.min + fraction as f64 * (self.max - self.min)
+    }
+
+    /// The thumb closest to the value, the end of a range when both are at the value.
+    fn closest_thumb(&self, value: f64) -> usize {
+        match self.value {
+            SliderValue::Single(_) => 0,
+            SliderValue::Range(start, end) => {
+                if (value - start).abs() < (value - end).abs() {
+                    0
+                } else {
+                    1
+                }
+            }
+        }
+    }
+
+    fn step_by(&mut self, steps: f64, cx: &mut ViewContext<Self>) {
+        let thumb = self.active_thumb;
+        let value = match thumb {
+            0 => self.value.start(),
+            _ => self.value.end(),
+        };
+        self.commit_thumb(thumb, value + self.step * steps, cx);
+    }
+
+    fn increment(&mut self, _: &Increment, cx: &mut ViewContext<Self>) {
+        self.step_by(1., cx);
+    }
+
+    fn decrement(&mut self, _: &Decrement, cx: &mut ViewContext<Self>) {
+        self.step_by(-1., cx);
+    }
+
+    fn increment_page(&mut self, _: &IncrementPage, cx: &mut ViewContext<Self>) {
+        self.step_by(PAGE_STEPS, cx);
+    }
+
+    fn decrement_page(&mut self, _: &DecrementPage, cx: &mut ViewContext<Self>) {
+        self.step_by(-PAGE_STEPS, cx);
+    }
+
+    fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
+        self.commit_thumb(self.active_thumb, self.min, cx);
+    }
+
+    fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
+        self.commit_thumb(self.active_thumb, self.max, cx);
+    }
+
+    /// Move the closest thumb to a click on the track.
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        cx.focus(&self.focus_handle);
+        let value = self.value_at(event.position, self.bounds);
+        let thumb = self.closest_thumb(value);
+        self.commit_thumb(thumb, value, cx);
+    }
+
+    fn on_drag_move(&mut self, event: &DragMoveEvent<DragThumb>, cx: &mut ViewContext<Self>) {
+        let drag = event.drag(cx);
+        if drag.slider != cx.view().entity_id() || self.disabled {
+            return;
+        }
+
+        let thumb = drag.thumb;
+        self.dragging = true;
+        let value = self.value_at(event.event.position, event.bounds);
+        self.move_thumb(thumb, value, cx);
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        if self.dragging {
+            self.dragging = false;
+            cx.emit(SliderEvent::Release(self.value));
+        }
+    }
+
+    fn render_thumb(
+        &self,
+        thumb: usize,
+        value: f64,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let slider = cx.view().entity_id();
+        let fraction = relative(self.fraction(value));
+        let focused = self.focus_handle.is_focused(cx) && self.active_thumb == thumb;
+        let offset = -(THUMB_SIZE * 0.5);
+        let color = if self.disabled {
+            cx.theme().muted_foreground
+        } else {
+            cx.theme().primary
+        };
+
+        div()
+            .id(ElementId::NamedInteger("thumb".into(), thumb))
+            .absolute()
+            .size(THUMB_SIZE)
+            .rounded_full()
+            .border_2()
+            .border_color(color)
+            .bg(cx.theme().background)
+            .when(focused, |this| this.shadow_md())
+            .map(|this| match self.axis {
+                Axis::Horizontal => this.top_0().left(fraction).ml(offset),
+                Axis::Vertical => this.left_0().bottom(fraction).mb(offset),
+            })
+            .when(!self.disabled, |this| {
+                this.cursor_grab()
+                    .on_drag(DragThumb { slider, thumb }, |drag, cx| {
+                        cx.stop_propagation();
+                        cx.new_view(|_| drag.clone())
+                    })
+            })
+    }
+
+    fn render_track(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let (start, end) = match self.value {
+            SliderValue::Single(value) => (self.fraction(self.min), self.fraction(value)),
+            SliderValue::Range(start, end) => (self.fraction(start), self.fraction(end)),
+        };
+        let ticks = self
+            .ticks
+            .filter(|interval| *interval > 0.)
+            .map(|interval| {
+                let count = ((self.max - self.min) / interval).floor() as usize;
+                (0..=count)
+                    .map(|ix| self.fraction(self.min + ix as f64 * interval))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let thumbs = self.value.thumbs();
+
+        div()
+            .id("track")
+            .relative()
+            .flex_1()
+            .map(|this| match self.axis {
+                Axis::Horizontal => this.w_full().h(THUMB_SIZE),
+                Axis::Vertical => this.h_full().w(THUMB_SIZE),
+            })
+            .when(!self.disabled, |this| this.cursor_pointer())
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_drag_move(cx.listener(Self::on_drag_move))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |slider, _| slider.bounds = bounds);
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            // The rail, with the selected part filled.
+            .child(
+                div()
+                    .absolute()
+                    .rounded_full()
+                    .bg(cx.theme().border)
+                    .map(|this| match self.axis {
+                        Axis::Horizontal => this
+                            .left_0()
+                            .right_0()
+                            .top((THUMB_SIZE - RAIL_SIZE) * 0.5)
+                            .h(RAIL_SIZE),
+                        Axis::Vertical => this
+                            .top_0()
+                            .bottom_0()
+                            .left((THUMB_SIZE - RAIL_SIZE) * 0.5)
+                            .w(RAIL_SIZE),
+                    })
+                    .child(
+                        div()
+                            .absolute()
+                            .rounded_full()
+                            .bg(if self.disabled {
+                                cx.theme().muted_foreground
+                            } else {
+                                cx.theme().primary
+                            })
+                            .map(|this| match self.axis {
+                                Axis::Horizontal => {
+                                    this.h_full().left(relative(start)).w(relative(end - start))
+                                }
+                                Axis::Vertical => this
+                                    .w_full()
+                                    .bottom(relative(start))
+                                    .h(relative(end - start)),
+                            }),
+                    ),
+            )
+            .children(ticks.into_iter().map(|fraction| {
+                div()
+                    .absolute()
+                    .bg(cx.theme().muted_foreground)
+                    .map(|this| match self.axis {
+                        Axis::Horizontal => {
+                            this.top_full().left(relative(fraction)).w(px(1.)).h(px(4.))
+                        }
+                        Axis::Vertical => this
+                            .left_full()
+                            .bottom(relative(fraction))
+                            .h(px(1.))
+                            .w(px(4.)),
+                    })
+            }))
+            .children(
+                thumbs
+                    .into_iter()
+                    .enumerate()
+                    .map(|(thumb, value)| self.render_thumb(thumb, value, cx)),
+            )
+    }
+}
+
+impl EventEmitter<SliderEvent> for Slider {}
+
+impl FocusableView for Slider {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Slider {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(|input| div().w(px(72.)).child(input.clone()));
+
+        match self.axis {
+            Axis::Horizontal => h_flex().w_full().gap_3().py_1(),
+            Axis::Vertical => v_flex().h_full().gap_3().px_1(),
+        }
+        .id("slider")
+        .key_context(CONTEXT)
+        .track_focus(&self.focus_handle)
+        .when(!self.disabled, |this| {
+            this.on_action(cx.listener(Self::increment))
+                .on_action(cx.listener(Self::decrement))
+                .on_action(cx.listener(Self::increment_page))
+                .on_action(cx.listener(Self::decrement_page))
+                .on_action(cx.listener(Self::home))
+                .on_action(cx.listener(Self::end))
+        })
+        .child(self.render_track(cx))
+        .children(inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+
+    #[test]
+    fn test_snap() {
+        assert_eq!(snap(42.4, 0., 100., 1.), 42.);
+        assert_eq!(snap(42.5, 0., 100., 1.), 43.);
+        assert_eq!(snap(7., 0., 100., 5.), 5.);
+        assert_eq!(snap(8., 0., 100., 5.), 10.);
+        // Steps count from the minimum.
+        assert_eq!(snap(7., 1., 100., 5.), 6.);
+        assert_eq!(snap(-20., 0., 100., 1.), 0.);
+        assert_eq!(snap(120., 0., 100., 1.), 100.);
+        // The maximum is reachable even off the step grid.
+        assert_eq!(snap(99., 0., 99., 10.), 99.);
+        assert!((snap(0.33, 0., 1., 0.1) - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_with_thumb() {
+        assert_eq!(
+            SliderValue::Single(10.).with_thumb(0, 20.),
+            SliderValue::Single(20.)
+        );
+
+        let range = SliderValue::Range(20., 60.);
+        assert_eq!(range.with_thumb(0, 30.), SliderValue::Range(30., 60.));
+        assert_eq!(range.with_thumb(1, 80.), SliderValue::Range(20., 80.));
+        // A thumb stops at the other end of the range.
+        assert_eq!(range.with_thumb(0, 70.), SliderValue::Range(60., 60.));
+        assert_eq!(range.with_thumb(1, 10.), SliderValue::Range(20., 20.));
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(precision(1.), 0);
+        assert_eq!(precision(10.), 0);
+        assert_eq!(precision(0.5), 1);
+        assert_eq!(precision(0.1), 1);
+        assert_eq!(precision(0.25), 2);
+        assert_eq!(precision(0.001), 3);
+        assert_eq!(precision(1. / 3.), 6);
+    }
+
+    #[gpui::test]
+    fn test_invalid_step_is_ignored(cx: &mut TestAppContext) {
+        // The step of 5 is kept, the value snaps to it.
+        for step in [5., 0., -1., f64::NAN, f64::INFINITY] {
+            let (slider, window_cx) =
+                cx.add_window_view(|cx| Slider::new(cx).step(5.).step(step).default_value(42.));
+            slider.update(window_cx, |this, _| {
+                assert_eq!(this.step, 5.);
+                assert_eq!(this.value, SliderValue::Single(40.));
+            });
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use gpui::{
+    percentage, px, svg, Animation, AnimationExt as _, Hsla, IntoElement, Pixels, RenderOnce,
+    Styled as _, Transformation, WindowContext,
+};
+use ti::theme::ActiveTheme;
+
+/// A loading indicator, rotating one of the loader icons.
+#[derive(IntoElement)]
+pub struct Spinner {
+    circle: bool,
+    size: Pixels,
+    color: Option<Hsla>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self {
+            circle: false,
+            size: px(16.),
+            color: None,
+        }
+    }
+
+    /// Rotate the open circle icon, instead of the rays.
+    pub fn circle(mut self) -> Self {
+        self.circle = true;
+        self
+    }
+
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = size.into();
+        self
+    }
+
+    /// The color of the icon, the muted foreground of the theme by default.
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for Spinner {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let path = if self.circle {
+            "icons/loader-circle.svg"
+        } else {
+            "icons/loader.svg"
+        };
+
+        svg()
+            .path(path)
+            .flex_none()
+            .size(self.size)
+            .text_color(self.color.unwrap_or(cx.theme().muted_foreground))
+            .with_animation(
+                "spinner",
+                Animation::new(Duration::from_secs(1)).repeat(),
+                |this, delta| this.with_transformation(Transformation::rotate(percentage(delta))),
+            )
+    }
+}
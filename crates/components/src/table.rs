@@ -0,0 +1,612 @@
+use std::collections::BTreeSet;
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, svg, uniform_list, AppContext, ClickEvent,
+    DragMoveEvent, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, Modifiers, ParentElement as _, Pixels, Render, SharedString,
+    StatefulInteractiveElement as _, Styled as _, UniformListScrollHandle, ViewContext,
+    VisualContext as _,
+};
+use ti::{h_flex, label::Label, theme::ActiveTheme, v_flex};
+
+actions!(table, [SelectPrev, SelectNext]);
+
+const CONTEXT: &str = "Table";
+const ROW_HEIGHT: Pixels = Pixels(28.);
+const MIN_COLUMN_WIDTH: Pixels = Pixels(40.);
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+    ]);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    #[default]
+    Single,
+    /// Cmd-click (ctrl-click on Linux and Windows) toggles a row, shift-click selects a range.
+    Multiple,
+}
+
+/// A column of a [`Table`].
+#[derive(Clone)]
+pub struct Column {
+    pub name: SharedString,
+    pub width: Pixels,
+    pub sortable: bool,
+    /// Align the cells to the right, for numbers.
+    pub align_right: bool,
+}
+
+impl Column {
+    pub fn new(name: impl Into<SharedString>, width: Pixels) -> Self {
+        Self {
+            name: name.into(),
+            width,
+            sortable: false,
+            align_right: false,
+        }
+    }
+
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    pub fn align_right(mut self) -> Self {
+        self.align_right = true;
+        self
+    }
+}
+
+/// Provides the columns and the cells of a [`Table`].
+pub trait TableDelegate: Sized + 'static {
+    fn columns(&self) -> Vec<Column>;
+
+    fn rows_count(&self) -> usize;
+
+    /// Render the cell of the row, `col_ix` is the index in [`Self::columns`].
+    fn render_cell(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement;
+
+    /// Sort the rows by the column, only called for sortable columns.
+    fn sort(&mut self, _col_ix: usize, _order: SortOrder, _cx: &mut ViewContext<Table<Self>>) {}
+}
+
+pub enum TableEvent {
+    /// The selected rows, in ascending order.
+    SelectionChanged(Vec<usize>),
+}
+
+#[derive(Clone, Render)]
+struct ResizeColumn {
+    table: EntityId,
+    col_ix: usize,
+}
+
+#[derive(Clone)]
+struct DraggedColumn {
+    table: EntityId,
+    col_ix: usize,
+    name: SharedString,
+}
+
+impl Render for DraggedColumn {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .bg(cx.theme().background)
+            .text_sm()
+            .child(self.name.clone())
+    }
+}
+
+/// A table that only renders its visible rows, with a header that stays on top.
+///
+/// Columns can be resized from the right edge of their header, reordered by dragging their
+/// header, and sorted by clicking it.
+pub struct Table<D: TableDelegate> {
+    focus_handle: FocusHandle,
+    delegate: D,
+    columns: Vec<Column>,
+    /// The indices in `columns`, in display order.
+    column_order: Vec<usize>,
+    sort: Option<(usize, SortOrder)>,
+    selection_mode: SelectionMode,
+    selected_rows: BTreeSet<usize>,
+    /// The row a shift-click selects from.
+    anchor_row: Option<usize>,
+    scroll_handle: UniformListScrollHandle,
+}
+
+impl<D: TableDelegate> Table<D> {
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let columns = delegate.columns();
+        Self {
+            focus_handle: cx.focus_handle(),
+            column_order: (0..columns.len()).collect(),
+            columns,
+            delegate,
+            sort: None,
+            selection_mode: SelectionMode::default(),
+            selected_rows: BTreeSet::new(),
+            anchor_row: None,
+            scroll_handle: UniformListScrollHandle::new(),
+        }
+    }
+
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    pub fn set_selection_mode(&mut self, mode: SelectionMode, cx: &mut ViewContext<Self>) {
+        self.selection_mode = mode;
+        if mode == SelectionMode::Single && self.selected_rows.len() > 1 {
+            let first = self.selected_rows.first().copied();
+            self.set_selection(first.into_iter().collect(), cx);
+        }
+        cx.notify();
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    pub fn delegate_mut(&mut self) -> &mut D {
+        &mut self.delegate
+    }
+
+    pub fn selected_rows(&self) -> Vec<usize> {
+        self.selected_rows.iter().copied().collect()
+    }
+
+    fn set_selection(&mut self, rows: BTreeSet<usize>, cx: &mut ViewContext<Self>) {
+        if rows != self.selected_rows {
+            self.selected_rows = rows;
+            cx.emit(TableEvent::SelectionChanged(self.selected_rows()));
+        }
+        cx.notify();
+    }
+
+    fn select_row(&mut self, row_ix: usize, modifiers: Modifiers, cx: &mut ViewContext<Self>) {
+        cx.focus(&self.focus_handle);
+
+        let mut rows = BTreeSet::new();
+        match self.selection_mode {
+            SelectionMode::Multiple if modifiers.shift => {
+                let anchor = self.anchor_row.unwrap_or(row_ix);
+                rows.extend(anchor.min(row_ix)..=anchor.max(row_ix));
+                self.set_selection(rows, cx);
+                return;
+            }
+            SelectionMode::Multiple if modifiers.platform || modifiers.control => {
+                rows = self.selected_rows.clone();
+                if !rows.remove(&row_ix) {
+                    rows.insert(row_ix);
+                }
+            }
+            _ => {
+                rows.insert(row_ix);
+            }
+        }
+        self.anchor_row = Some(row_ix);
+        self.set_selection(rows, cx);
+    }
+
+    fn select_by(&mut self, delta: isize, cx: &mut ViewContext<Self>) {
+        let count = self.delegate.rows_count();
+        if count == 0 {
+            return;
+        }
+
+        let row_ix = match self.anchor_row {
+            Some(row_ix) => (row_ix as isize + delta).clamp(0, count as isize - 1) as usize,
+            None => 0,
+        };
+        self.select_row(row_ix, Modifiers::default(), cx);
+        self.scroll_handle.scroll_to_item(row_ix);
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.select_by(-1, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.select_by(1, cx);
+    }
+
+    fn toggle_sort(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
+        if !self.columns[col_ix].sortable {
+            return;
+        }
+
+        let order = match self.sort {
+            Some((sorted, SortOrder::Ascending)) if sorted == col_ix => SortOrder::Descending,
+            _ => SortOrder::Ascending,
+        };
+        self.sort = Some((col_ix, order));
+        self.delegate.sort(col_ix, order, cx);
+        // The selected row indices no longer point at the same rows.
+        self.anchor_row = None;
+        self.set_selection(BTreeSet::new(), cx);
+    }
+
+    /// Move the column to the display position of `target_col_ix`.
+    fn move_column(&mut self, col_ix: usize, target_col_ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(from) = self.column_order.iter().position(|ix| *ix == col_ix) else {
+            return;
+        };
+        let Some(to) = self.column_order.iter().position(|ix| *ix == target_col_ix) else {
+            return;
+        };
+
+        let col_ix = self.column_order.remove(from);
+        self.column_order.insert(to, col_ix);
+        cx.notify();
+    }
+
+    fn render_header_cell(&self, col_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let table = cx.view().entity_id();
+        let column = &self.columns[col_ix];
+        let sort_icon = match self.sort {
+            Some((sorted, SortOrder::Ascending)) if sorted == col_ix => {
+                Some("icons/sort-ascending.svg")
+            }
+            Some((sorted, SortOrder::Descending)) if sorted == col_ix => {
+                Some("icons/sort-descending.svg")
+            }
+            _ => None,
+        };
+
+        h_flex()
+            .id(("table-header", col_ix))
+            .relative()
+            .flex_none()
+            .w(column.width)
+            .h_full()
+            .px_2()
+            .gap_1()
+            .items_center()
+            .when(column.align_right, |this| this.justify_end())
+            .border_r_1()
+            .border_color(cx.theme().border)
+            .child(
+                Label::new(column.name.clone())
+                    .text_sm()
+                    .whitespace_nowrap(),
+            )
+            .when_some(sort_icon, |this, icon| {
+                this.child(svg().path(icon).size_4().text_color(cx.theme().foreground))
+            })
+            .when(column.sortable, |this| {
+                this.cursor_pointer()
+                    .on_click(cx.listener(move |this, _, cx| this.toggle_sort(col_ix, cx)))
+            })
+            .on_drag(
+                DraggedColumn {
+                    table,
+                    col_ix,
+                    name: column.name.clone(),
+                },
+                |drag, cx| cx.new_view(|_| drag.clone()),
+            )
+            .drag_over::<DraggedColumn>(|style, _, cx| style.bg(cx.theme().drop_target))
+            .on_drop(cx.listener(move |this, drag: &DraggedColumn, cx| {
+                if drag.table == table {
+                    this.move_column(drag.col_ix, col_ix, cx);
+                }
+            }))
+            .on_drag_move(
+                cx.listener(move |this, event: &DragMoveEvent<ResizeColumn>, cx| {
+                    let drag = event.drag(cx);
+                    if drag.table != table || drag.col_ix != col_ix {
+                        return;
+                    }
+
+                    let width =
+                        (event.event.position.x - event.bounds.left()).max(MIN_COLUMN_WIDTH);
+                    if this.columns[col_ix].width != width {
+                        this.columns[col_ix].width = width;
+                        cx.notify();
+                    }
+                }),
+            )
+            .child(
+                div()
+                    .id(("table-resize-handle", col_ix))
+                    .absolute()
+                    .top_0()
+                    .right(px(-3.))
+                    .w(px(6.))
+                    .h_full()
+                    .cursor_col_resize()
+                    .on_drag(ResizeColumn { table, col_ix }, |drag, cx| {
+                        cx.stop_propagation();
+                        cx.new_view(|_| drag.clone())
+                    }),
+            )
+    }
+
+    fn render_row(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let selected = self.selected_rows.contains(&row_ix);
+
+        h_flex()
+            .id(("table-row", row_ix))
+            .h(ROW_HEIGHT)
+            .map(|this| {
+                if selected {
+                    this.bg(cx.theme().list_active)
+                } else if row_ix % 2 == 0 {
+                    this.bg(cx.theme().list)
+                } else {
+                    this.bg(cx.theme().list_even)
+                }
+            })
+            .children(self.column_order.iter().map(|col_ix| {
+                let column = &self.columns[*col_ix];
+                h_flex()
+                    .flex_none()
+                    .w(column.width)
+                    .h_full()
+                    .px_2()
+                    .items_center()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_sm()
+                    .when(column.align_right, |this| this.justify_end())
+                    .child(self.delegate.render_cell(row_ix, *col_ix, cx))
+            }))
+            .on_click(cx.listener(move |this, event: &ClickEvent, cx| {
+                this.select_row(row_ix, event.down.modifiers, cx)
+            }))
+    }
+}
+
+impl<D: TableDelegate> EventEmitter<TableEvent> for Table<D> {}
+
+impl<D: TableDelegate> FocusableView for Table<D> {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<D: TableDelegate> Render for Table<D> {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let rows_count = self.delegate.rows_count();
+        let total_width = self
+            .columns
+            .iter()
+            .fold(px(0.), |width, column| width + column.width);
+
+        div()
+            .id("table")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .size_full()
+            .overflow_x_scroll()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded_md()
+            .child(
+                v_flex()
+                    .h_full()
+                    .min_w(total_width)
+                    .child(
+                        h_flex()
+                            .flex_none()
+                            .h(ROW_HEIGHT + px(4.))
+                            .border_b_1()
+                            .border_color(cx.theme().border)
+                            .bg(cx.theme().tab_bar)
+                            .children(
+                                self.column_order
+                                    .clone()
+                                    .into_iter()
+                                    .map(|col_ix| self.render_header_cell(col_ix, cx)),
+                            ),
+                    )
+                    .child(
+                        uniform_list(view, "table-rows", rows_count, |this, range, cx| {
+                            range.map(|row_ix| this.render_row(row_ix, cx)).collect()
+                        })
+                        .flex_grow()
+                        .track_scroll(self.scroll_handle.clone()),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{TestAppContext, View, VisualTestContext};
+    use ti::theme::Theme;
+
+    use super::*;
+
+    struct TestDelegate {
+        rows: Vec<usize>,
+    }
+
+    impl TableDelegate for TestDelegate {
+        fn columns(&self) -> Vec<Column> {
+            vec![
+                Column::new("Name", px(100.)),
+                Column::new("Value", px(80.)).sortable(),
+                Column::new("Note", px(120.)),
+            ]
+        }
+
+        fn rows_count(&self) -> usize {
+            self.rows.len()
+        }
+
+        fn render_cell(
+            &self,
+            row_ix: usize,
+            _col_ix: usize,
+            _cx: &mut ViewContext<Table<Self>>,
+        ) -> impl IntoElement {
+            self.rows[row_ix].to_string()
+        }
+
+        fn sort(&mut self, _col_ix: usize, order: SortOrder, _cx: &mut ViewContext<Table<Self>>) {
+            match order {
+                SortOrder::Ascending => self.rows.sort(),
+                SortOrder::Descending => self.rows.sort_by(|a, b| b.cmp(a)),
+            }
+        }
+    }
+
+    fn build_table(
+        mode: SelectionMode,
+        cx: &mut TestAppContext,
+    ) -> (View<Table<TestDelegate>>, &mut VisualTestContext) {
+        cx.update(|cx| Theme::init(cx));
+        cx.add_window_view(|cx| {
+            let delegate = TestDelegate {
+                rows: vec![3, 1, 4, 1, 5, 9],
+            };
+            Table::new(delegate, cx).selection_mode(mode)
+        })
+    }
+
+    fn shift() -> Modifiers {
+        Modifiers {
+            shift: true,
+            ..Default::default()
+        }
+    }
+
+    fn secondary() -> Modifiers {
+        Modifiers {
+            control: true,
+            ..Default::default()
+        }
+    }
+
+    #[gpui::test]
+    fn test_select_range_from_anchor(cx: &mut TestAppContext) {
+        let (table, cx) = build_table(SelectionMode::Multiple, cx);
+        table.update(cx, |this, cx| {
+            this.select_row(2, Modifiers::default(), cx);
+            this.select_row(4, shift(), cx);
+            assert_eq!(this.selected_rows(), [2, 3, 4]);
+
+            // The anchor stays put, the range follows the shift-clicked row.
+            this.select_row(0, shift(), cx);
+            assert_eq!(this.selected_rows(), [0, 1, 2]);
+            assert_eq!(this.anchor_row, Some(2));
+        });
+    }
+
+    #[gpui::test]
+    fn test_toggle_rows(cx: &mut TestAppContext) {
+        let (table, cx) = build_table(SelectionMode::Multiple, cx);
+        table.update(cx, |this, cx| {
+            this.select_row(1, Modifiers::default(), cx);
+            this.select_row(3, secondary(), cx);
+            this.select_row(5, secondary(), cx);
+            assert_eq!(this.selected_rows(), [1, 3, 5]);
+
+            this.select_row(3, secondary(), cx);
+            assert_eq!(this.selected_rows(), [1, 5]);
+
+            // The toggled row becomes the anchor of a shift-click.
+            this.select_row(4, shift(), cx);
+            assert_eq!(this.selected_rows(), [3, 4]);
+
+            // A plain click selects only that row.
+            this.select_row(0, Modifiers::default(), cx);
+            assert_eq!(this.selected_rows(), [0]);
+        });
+    }
+
+    #[gpui::test]
+    fn test_single_selection(cx: &mut TestAppContext) {
+        let (table, cx) = build_table(SelectionMode::Single, cx);
+        table.update(cx, |this, cx| {
+            // Modifiers are ignored in single mode.
+            this.select_row(1, Modifiers::default(), cx);
+            this.select_row(3, shift(), cx);
+            assert_eq!(this.selected_rows(), [3]);
+            this.select_row(4, secondary(), cx);
+            assert_eq!(this.selected_rows(), [4]);
+
+            this.set_selection_mode(SelectionMode::Multiple, cx);
+            this.select_row(2, shift(), cx);
+            assert_eq!(this.selected_rows(), [2, 3, 4]);
+
+            // Switching back keeps only the first row.
+            this.set_selection_mode(SelectionMode::Single, cx);
+            assert_eq!(this.selected_rows(), [2]);
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_column(cx: &mut TestAppContext) {
+        let (table, cx) = build_table(SelectionMode::Single, cx);
+        table.update(cx, |this, cx| {
+            this.move_column(0, 2, cx);
+            assert_eq!(this.column_order, [1, 2, 0]);
+
+            // The target is a column index, not a display position.
+            this.move_column(0, 1, cx);
+            assert_eq!(this.column_order, [0, 1, 2]);
+
+            this.move_column(2, 0, cx);
+            assert_eq!(this.column_order, [2, 0, 1]);
+
+            // Moving a column onto itself keeps the order.
+            this.move_column(1, 1, cx);
+            assert_eq!(this.column_order, [2, 0, 1]);
+        });
+    }
+
+    #[gpui::test]
+    fn test_toggle_sort(cx: &mut TestAppContext) {
+        let (table, cx) = build_table(SelectionMode::Multiple, cx);
+        table.update(cx, |this, cx| {
+            // Columns that are not sortable are ignored.
+            this.toggle_sort(0, cx);
+            assert_eq!(this.sort, None);
+
+            this.select_row(1, Modifiers::default(), cx);
+            this.select_row(2, secondary(), cx);
+            this.toggle_sort(1, cx);
+            assert_eq!(this.sort, Some((1, SortOrder::Ascending)));
+            assert_eq!(this.delegate().rows, [1, 1, 3, 4, 5, 9]);
+            assert!(this.selected_rows().is_empty());
+            assert_eq!(this.anchor_row, None);
+
+            this.select_row(0, Modifiers::default(), cx);
+            this.toggle_sort(1, cx);
+            assert_eq!(this.sort, Some((1, SortOrder::Descending)));
+            assert_eq!(this.delegate().rows, [9, 5, 4, 3, 1, 1]);
+            assert!(this.selected_rows().is_empty());
+
+            // And back to ascending.
+            this.toggle_sort(1, cx);
+            assert_eq!(this.sort, Some((1, SortOrder::Ascending)));
+        });
+    }
+}
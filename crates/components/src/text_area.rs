@@ -0,0 +1,1021 @@
+use std::ops::Range;
+
+use gpui::{
+    actions, canvas, div, fill, point, prelude::FluentBuilder as _, px, size, AppContext, Bounds,
+    ClipboardItem, ElementInputHandler, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement, IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ParentElement as _, Pixels, Point, Render, ScrollHandle, SharedString,
+    StatefulInteractiveElement as _, Styled as _, Subscription, TextRun, UnderlineStyle,
+    ViewContext, ViewInputHandler, WrappedLine,
+};
+use ti::{
+    input::{Copy, Cut, InputEvent, Paste},
+    theme::ActiveTheme,
+};
+
+actions!(
+    text_area,
+    [
+        Backspace,
+        Delete,
+        Enter,
+        SecondaryEnter,
+        Left,
+        Right,
+        Up,
+        Down,
+        Home,
+        End,
+        SelectLeft,
+        SelectRight,
+        SelectUp,
+        SelectDown,
+        SelectHome,
+        SelectEnd,
+        SelectAll
+    ]
+);
+
+const CONTEXT: &str = "TextArea";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("backspace", Backspace, Some(CONTEXT)),
+        KeyBinding::new("delete", Delete, Some(CONTEXT)),
+        KeyBinding::new("enter", Enter, Some(CONTEXT)),
+        KeyBinding::new("left", Left, Some(CONTEXT)),
+        KeyBinding::new("right", Right, Some(CONTEXT)),
+        KeyBinding::new("up", Up, Some(CONTEXT)),
+        KeyBinding::new("down", Down, Some(CONTEXT)),
+        KeyBinding::new("home", Home, Some(CONTEXT)),
+        KeyBinding::new("end", End, Some(CONTEXT)),
+        KeyBinding::new("shift-left", SelectLeft, Some(CONTEXT)),
+        KeyBinding::new("shift-right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("shift-up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("shift-down", SelectDown, Some(CONTEXT)),
+        KeyBinding::new("shift-home", SelectHome, Some(CONTEXT)),
+        KeyBinding::new("shift-end", SelectEnd, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-enter", SecondaryEnter, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-a", SelectAll, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-c", Copy, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-x", Cut, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-v", Paste, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-enter", SecondaryEnter, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-a", SelectAll, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-c", Copy, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-x", Cut, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-v", Paste, Some(CONTEXT)),
+    ]);
+}
+
+/// The shaped text of the last frame, used for hit testing and vertical movement.
+#[derive(Clone)]
+struct TextAreaLayout {
+    lines: Vec<WrappedLine>,
+    bounds: Bounds<Pixels>,
+    line_height: Pixels,
+    /// The number of visual rows, wrapped lines included.
+    rows: usize,
+}
+
+/// What the canvas paints besides the text.
+struct TextAreaPaint {
+    layout: TextAreaLayout,
+    cursor: Option<Point<Pixels>>,
+    selection: Vec<Bounds<Pixels>>,
+}
+
+/// A multi-line text input.
+///
+/// Enter inserts a new line, `cmd-enter` (`ctrl-enter` on Linux and Windows) emits
+/// [`InputEvent::PressEnter`]. The same [`InputEvent`]s as `TextInput` are emitted.
+///
+/// Text comes in through the platform input handler like in `TextInput`, so IME composition
+/// works, and the selection is extended with shift and the arrow keys or by dragging.
+pub struct TextArea {
+    focus_handle: FocusHandle,
+    text: String,
+    /// The selected byte range of `text`, empty for a plain cursor.
+    selected_range: Range<usize>,
+    /// Whether the cursor is at the start of the selection.
+    selection_reversed: bool,
+    /// The text being composed by the IME.
+    marked_range: Option<Range<usize>>,
+    /// Whether the mouse is down, extending the selection as it moves.
+    selecting: bool,
+    placeholder: SharedString,
+    rows: usize,
+    max_rows: Option<usize>,
+    soft_wrap: bool,
+    disabled: bool,
+    scroll_handle: ScrollHandle,
+    scroll_to_cursor: bool,
+    last_layout: Option<TextAreaLayout>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TextArea {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let focus_handle = cx.focus_handle();
+        let _subscriptions = vec![
+            cx.on_focus(&focus_handle, |_, cx| cx.emit(InputEvent::Focus)),
+            cx.on_blur(&focus_handle, |_, cx| cx.emit(InputEvent::Blur)),
+        ];
+
+        Self {
+            focus_handle,
+            text: String::new(),
+            selected_range: 0..0,
+            selection_reversed: false,
+            marked_range: None,
+            selecting: false,
+            placeholder: SharedString::default(),
+            rows: 3,
+            max_rows: None,
+            soft_wrap: true,
+            disabled: false,
+            scroll_handle: ScrollHandle::new(),
+            scroll_to_cursor: false,
+            last_layout: None,
+            _subscriptions,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the number of visible rows, 3 by default.
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+
+    /// Grow with the content from `rows` up to `max_rows`, then scroll.
+    pub fn auto_grow(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Wrap long lines at the width of the text area, enabled by default.
+    pub fn soft_wrap(mut self, soft_wrap: bool) -> Self {
+        self.soft_wrap = soft_wrap;
+        self
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        cx.notify();
+    }
+
+    pub fn set_text(&mut self, text: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        self.text = text.into().to_string();
+        self.selected_range = self.text.len()..self.text.len();
+        self.selection_reversed = false;
+        self.marked_range = None;
+        self.scroll_to_cursor = true;
+        cx.notify();
+    }
+
+    pub fn text(&self) -> SharedString {
+        self.text.clone().into()
+    }
+
+    /// The selected text, empty without a selection.
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.selected_range.clone()]
+    }
+
+    /// The zero-based line and column, in characters, of the cursor.
+    pub fn cursor_position(&self) -> (usize, usize) {
+        let before = &self.text[..self.cursor_offset()];
+        let line_start = before.rfind('\n').map_or(0, |ix| ix + 1);
+        (
+            before.matches('\n').count(),
+            before[line_start..].chars().count(),
+        )
+    }
+
+    /// The byte offset of the cursor, at the moving end of the selection.
+    fn cursor_offset(&self) -> usize {
+        if self.selection_reversed {
+            self.selected_range.start
+        } else {
+            self.selected_range.end
+        }
+    }
+
+    fn replace(&mut self, range: Range<usize>, text: &str, cx: &mut ViewContext<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        self.text.replace_range(range.clone(), text);
+        let cursor = range.start + text.len();
+        self.selected_range = cursor..cursor;
+        self.selection_reversed = false;
+        self.marked_range = None;
+        self.scroll_to_cursor = true;
+        cx.emit(InputEvent::Change(self.text.clone().into()));
+        cx.notify();
+    }
+
+    /// Replace the selection, or the text being composed.
+    fn replace_selection(&mut self, text: &str, cx: &mut ViewContext<Self>) {
+        let range = self
+            .marked_range
+            .clone()
+            .unwrap_or(self.selected_range.clone());
+        self.replace(range, text, cx);
+    }
+
+    fn move_to(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
+        let offset = offset.min(self.text.len());
+        self.selected_range = offset..offset;
+        self.selection_reversed = false;
+        self.scroll_to_cursor = true;
+        cx.notify();
+    }
+
+    /// Move the cursor end of the selection, keeping the other end in place.
+    fn select_to(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
+        let offset = offset.min(self.text.len());
+        if self.selection_reversed {
+            self.selected_range.start = offset;
+        } else {
+            self.selected_range.end = offset;
+        }
+        if self.selected_range.end < self.selected_range.start {
+            self.selection_reversed = !self.selection_reversed;
+            self.selected_range = self.selected_range.end..self.selected_range.start;
+        }
+        self.scroll_to_cursor = true;
+        cx.notify();
+    }
+
+    fn previous_boundary(&self, offset: usize) -> usize {
+        self.text[..offset]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(ix, _)| ix)
+    }
+
+    fn next_boundary(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .chars()
+            .next()
+            .map_or(offset, |c| offset + c.len_utf8())
+    }
+
+    fn line_start(&self, offset: usize) -> usize {
+        self.text[..offset].rfind('\n').map_or(0, |ix| ix + 1)
+    }
+
+    fn line_end(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .find('\n')
+            .map_or(self.text.len(), |ix| offset + ix)
+    }
+
+    fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if self.selected_range.is_empty() {
+            self.select_to(self.previous_boundary(self.cursor_offset()), cx);
+        }
+        self.replace_selection("", cx);
+    }
+
+    fn delete(&mut self, _: &Delete, cx: &mut ViewContext<Self>) {
+        if self.selected_range.is_empty() {
+            self.select_to(self.next_boundary(self.cursor_offset()), cx);
+        }
+        self.replace_selection("", cx);
+    }
+
+    fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        self.replace_selection("\n", cx);
+    }
+
+    fn secondary_enter(&mut self, _: &SecondaryEnter, cx: &mut ViewContext<Self>) {
+        cx.emit(InputEvent::PressEnter);
+    }
+
+    fn left(&mut self, _: &Left, cx: &mut ViewContext<Self>) {
+        if self.selected_range.is_empty() {
+            self.move_to(self.previous_boundary(self.cursor_offset()), cx);
+        } else {
+            self.move_to(self.selected_range.start, cx);
+        }
+    }
+
+    fn right(&mut self, _: &Right, cx: &mut ViewContext<Self>) {
+        if self.selected_range.is_empty() {
+            self.move_to(self.next_boundary(self.cursor_offset()), cx);
+        } else {
+            self.move_to(self.selected_range.end, cx);
+        }
+    }
+
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        self.move_vertically(-1., false, cx);
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        self.move_vertically(1., false, cx);
+    }
+
+    fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
+        self.move_to(self.line_start(self.cursor_offset()), cx);
+    }
+
+    fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
+        self.move_to(self.line_end(self.cursor_offset()), cx);
+    }
+
+    fn select_left(&mut self, _: &SelectLeft, cx: &mut ViewContext<Self>) {
+        self.select_to(self.previous_boundary(self.cursor_offset()), cx);
+    }
+
+    fn select_right(&mut self, _: &SelectRight, cx: &mut ViewContext<Self>) {
+        self.select_to(self.next_boundary(self.cursor_offset()), cx);
+    }
+
+    fn select_up(&mut self, _: &SelectUp, cx: &mut ViewContext<Self>) {
+        self.move_vertically(-1., true, cx);
+    }
+
+    fn select_down(&mut self, _: &SelectDown, cx: &mut ViewContext<Self>) {
+        self.move_vertically(1., true, cx);
+    }
+
+    fn select_home(&mut self, _: &SelectHome, cx: &mut ViewContext<Self>) {
+        self.select_to(self.line_start(self.cursor_offset()), cx);
+    }
+
+    fn select_end(&mut self, _: &SelectEnd, cx: &mut ViewContext<Self>) {
+        self.select_to(self.line_end(self.cursor_offset()), cx);
+    }
+
+    fn select_all(&mut self, _: &SelectAll, cx: &mut ViewContext<Self>) {
+        self.selected_range = 0..self.text.len();
+        self.selection_reversed = false;
+        cx.notify();
+    }
+
+    fn copy(&mut self, _: &Copy, cx: &mut ViewContext<Self>) {
+        if !self.selected_range.is_empty() {
+            cx.write_to_clipboard(ClipboardItem::new(self.selected_text().to_string()));
+        }
+    }
+
+    fn cut(&mut self, _: &Cut, cx: &mut ViewContext<Self>) {
+        if !self.selected_range.is_empty() {
+            cx.write_to_clipboard(ClipboardItem::new(self.selected_text().to_string()));
+            self.replace_selection("", cx);
+        }
+    }
+
+    fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
+        if let Some(item) = cx.read_from_clipboard() {
+            let text = item.text().replace("\r\n", "\n");
+            self.replace_selection(&text, cx);
+        }
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        cx.focus(&self.focus_handle);
+        self.selecting = true;
+        if let Some(offset) = self.offset_for_position(event.position) {
+            if event.modifiers.shift {
+                self.select_to(offset, cx);
+            } else {
+                self.move_to(offset, cx);
+            }
+        }
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+        if self.selecting {
+            if let Some(offset) = self.offset_for_position(event.position) {
+                self.select_to(offset, cx);
+            }
+        }
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut ViewContext<Self>) {
+        self.selecting = false;
+    }
+
+    /// Move the cursor by the given number of visual rows, extending the selection if `select`.
+    fn move_vertically(&mut self, rows: f32, select: bool, cx: &mut ViewContext<Self>) {
+        let Some(layout) = self.last_layout.as_ref() else {
+            return;
+        };
+        let Some(position) = self.point_for_offset(layout, self.cursor_offset()) else {
+            return;
+        };
+
+        let target = layout.bounds.origin
+            + point(position.x, position.y + layout.line_height * (rows + 0.5));
+        let offset = if target.y < layout.bounds.top() {
+            0
+        } else {
+            self.offset_for_position(target).unwrap_or(self.text.len())
+        };
+        if select {
+            self.select_to(offset, cx);
+        } else {
+            self.move_to(offset, cx);
+        }
+    }
+
+    /// The position of the given byte offset, relative to the text origin.
+    fn point_for_offset(&self, layout: &TextAreaLayout, offset: usize) -> Option<Point<Pixels>> {
+        if self.text.is_empty() {
+            return Some(Point::default());
+        }
+
+        let mut line_start = 0;
+        let mut y = px(0.);
+        for line in &layout.lines {
+            let line_end = line_start + line.len();
+            if offset <= line_end {
+                let position = line.position_for_index(offset - line_start, layout.line_height)?;
+                return Some(point(position.x, y + position.y));
+            }
+            line_start = line_end + 1;
+            y += line.size(layout.line_height).height;
+        }
+        None
+    }
+
+    /// The highlight of the selection, one rectangle per visual row, relative to the text origin.
+    fn selection_bounds(&self, layout: &TextAreaLayout) -> Vec<Bounds<Pixels>> {
+        if self.selected_range.is_empty() {
+            return Vec::new();
+        }
+        let (Some(start), Some(end)) = (
+            self.point_for_offset(layout, self.selected_range.start),
+            self.point_for_offset(layout, self.selected_range.end),
+        ) else {
+            return Vec::new();
+        };
+
+        let line_height = layout.line_height;
+        let width = layout.bounds.size.width;
+        if start.y == end.y {
+            return vec![Bounds::from_corners(
+                start,
+                point(end.x, end.y + line_height),
+            )];
+        }
+
+        let mut bounds = vec![Bounds::from_corners(
+            start,
+            point(width, start.y + line_height),
+        )];
+        if end.y > start.y + line_height {
+            bounds.push(Bounds::from_corners(
+                point(px(0.), start.y + line_height),
+                point(width, end.y),
+            ));
+        }
+        bounds.push(Bounds::from_corners(
+            point(px(0.), end.y),
+            point(end.x, end.y + line_height),
+        ));
+        bounds
+    }
+
+    /// The byte offset of the text at the given window position.
+    fn offset_for_position(&self, position: Point<Pixels>) -> Option<usize> {
+        if self.text.is_empty() {
+            return Some(0);
+        }
+
+        let layout = self.last_layout.as_ref()?;
+        let position = position - layout.bounds.origin;
+        if position.y < px(0.) {
+            return Some(0);
+        }
+
+        let mut line_start = 0;
+        let mut y = px(0.);
+        for line in &layout.lines {
+            let height = line.size(layout.line_height).height;
+            if position.y < y + height {
+                let ix = line
+                    .index_for_position(point(position.x, position.y - y), layout.line_height)
+                    .unwrap_or_else(|ix| ix);
+                return Some(line_start + ix.min(line.len()));
+            }
+            line_start += line.len() + 1;
+            y += height;
+        }
+        None
+    }
+
+    fn layout_text(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        cx: &mut ViewContext<Self>,
+    ) -> TextAreaLayout {
+        let style = cx.text_style();
+        let font_size = style.font_size.to_pixels(cx.rem_size());
+        let line_height = cx.line_height();
+        let runs = if self.text.is_empty() {
+            vec![TextRun {
+                color: cx.theme().muted_foreground,
+                ..style.to_run(self.placeholder.len())
+            }]
+        } else if let Some(marked_range) = self.marked_range.clone() {
+            // Underline the text being composed.
+            let marked = TextRun {
+                underline: Some(UnderlineStyle {
+                    color: Some(style.color),
+                    thickness: px(1.),
+                    wavy: false,
+                }),
+                ..style.to_run(marked_range.len())
+            };
+            [
+                style.to_run(marked_range.start),
+                marked,
+                style.to_run(self.text.len() - marked_range.end),
+            ]
+            .into_iter()
+            .filter(|run| run.len > 0)
+            .collect()
+        } else {
+            vec![style.to_run(self.text.len())]
+        };
+        let text: SharedString = if self.text.is_empty() {
+            self.placeholder.clone()
+        } else {
+            self.text.clone().into()
+        };
+        let wrap_width = self.soft_wrap.then_some(bounds.size.width);
+        let lines = cx
+            .text_system()
+            .shape_text(text, font_size, &runs, wrap_width)
+            .map(|lines| lines.into_iter().collect::<Vec<_>>())
+            .unwrap_or_default();
+        let rows = lines
+            .iter()
+            .map(|line| (line.size(line_height).height / line_height).round() as usize)
+            .sum::<usize>()
+            .max(1);
+
+        let layout = TextAreaLayout {
+            lines,
+            bounds,
+            line_height,
+            rows,
+        };
+        if self.last_layout.as_ref().map(|layout| layout.rows) != Some(rows) {
+            cx.notify();
+        }
+        if std::mem::take(&mut self.scroll_to_cursor) {
+            self.scroll_cursor_into_view(&layout);
+        }
+        self.last_layout = Some(layout.clone());
+        layout
+    }
+
+    fn scroll_cursor_into_view(&self, layout: &TextAreaLayout) {
+        let Some(cursor) = self.point_for_offset(layout, self.cursor_offset()) else {
+            return;
+        };
+
+        let offset = self.scroll_handle.offset();
+        let viewport_height = self.scroll_handle.bounds().size.height;
+        if cursor.y + offset.y < px(0.) {
+            self.scroll_handle.set_offset(point(offset.x, -cursor.y));
+        } else if cursor.y + layout.line_height + offset.y > viewport_height {
+            self.scroll_handle.set_offset(point(
+                offset.x,
+                viewport_height - cursor.y - layout.line_height,
+            ));
+        }
+    }
+}
+
+/// Convert a UTF-16 offset, as used by the platform input handler, to a byte offset of `text`.
+fn offset_from_utf16(text: &str, offset: usize) -> usize {
+    let mut utf16_count = 0;
+    for (ix, c) in text.char_indices() {
+        if utf16_count >= offset {
+            return ix;
+        }
+        utf16_count += c.len_utf16();
+    }
+    text.len()
+}
+
+/// Convert a byte offset of `text` to a UTF-16 offset.
+fn offset_to_utf16(text: &str, offset: usize) -> usize {
+    text[..offset].chars().map(char::len_utf16).sum()
+}
+
+fn range_from_utf16(text: &str, range: &Range<usize>) -> Range<usize> {
+    offset_from_utf16(text, range.start)..offset_from_utf16(text, range.end)
+}
+
+fn range_to_utf16(text: &str, range: &Range<usize>) -> Range<usize> {
+    offset_to_utf16(text, range.start)..offset_to_utf16(text, range.end)
+}
+
+impl ViewInputHandler for TextArea {
+    fn text_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        _: &mut ViewContext<Self>,
+    ) -> Option<String> {
+        let range = range_from_utf16(&self.text, &range_utf16);
+        Some(self.text[range].to_string())
+    }
+
+    fn selected_text_range(&mut self, _: &mut ViewContext<Self>) -> Option<Range<usize>> {
+        Some(range_to_utf16(&self.text, &self.selected_range))
+    }
+
+    fn marked_text_range(&self, _: &mut ViewContext<Self>) -> Option<Range<usize>> {
+        self.marked_range
+            .as_ref()
+            .map(|range| range_to_utf16(&self.text, range))
+    }
+
+    fn unmark_text(&mut self, cx: &mut ViewContext<Self>) {
+        self.marked_range = None;
+        cx.notify();
+    }
+
+    fn replace_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match range_utf16 {
+            Some(range_utf16) => {
+                let range = range_from_utf16(&self.text, &range_utf16);
+                self.replace(range, text, cx);
+            }
+            None => self.replace_selection(text, cx),
+        }
+    }
+
+    fn replace_and_mark_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        new_text: &str,
+        new_selected_range_utf16: Option<Range<usize>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if self.disabled {
+            return;
+        }
+
+        let range = range_utf16
+            .map(|range_utf16| range_from_utf16(&self.text, &range_utf16))
+            .or(self.marked_range.clone())
+            .unwrap_or(self.selected_range.clone());
+
+        self.text.replace_range(range.clone(), new_text);
+        self.marked_range =
+            (!new_text.is_empty()).then(|| range.start..range.start + new_text.len());
+        self.selected_range = match new_selected_range_utf16 {
+            Some(selected_utf16) => {
+                let selected = range_from_utf16(new_text, &selected_utf16);
+                range.start + selected.start..range.start + selected.end
+            }
+            None => range.start + new_text.len()..range.start + new_text.len(),
+        };
+        self.selection_reversed = false;
+        self.scroll_to_cursor = true;
+        cx.emit(InputEvent::Change(self.text.clone().into()));
+        cx.notify();
+    }
+
+    fn bounds_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        element_bounds: Bounds<Pixels>,
+        _: &mut ViewContext<Self>,
+    ) -> Option<Bounds<Pixels>> {
+        let layout = self.last_layout.as_ref()?;
+        let range = range_from_utf16(&self.text, &range_utf16);
+        let start = self.point_for_offset(layout, range.start)?;
+        let end = self
+            .point_for_offset(layout, range.end)
+            .filter(|end| end.y == start.y)
+            .unwrap_or(start);
+        Some(Bounds::from_corners(
+            element_bounds.origin + start,
+            element_bounds.origin + point(end.x, start.y + layout.line_height),
+        ))
+    }
+}
+
+impl EventEmitter<InputEvent> for TextArea {}
+
+impl FocusableView for TextArea {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TextArea {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let focus_handle = self.focus_handle.clone();
+        let focused = self.focus_handle.is_focused(cx);
+        let disabled = self.disabled;
+        let line_height = cx.line_height();
+        let content_rows = self.last_layout.as_ref().map_or(1, |layout| layout.rows);
+        let visible_rows = match self.max_rows {
+            Some(max_rows) => content_rows.clamp(self.rows, max_rows.max(self.rows)),
+            None => self.rows,
+        };
+        let cursor_color = cx.theme().primary;
+        let selection_color = cx.theme().primary.opacity(0.25);
+
+        div()
+            .id("text-area")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .when(!self.disabled, |this| {
+                this.on_action(cx.listener(Self::backspace))
+                    .on_action(cx.listener(Self::delete))
+                    .on_action(cx.listener(Self::enter))
+                    .on_action(cx.listener(Self::secondary_enter))
+                    .on_action(cx.listener(Self::cut))
+                    .on_action(cx.listener(Self::paste))
+            })
+            .on_action(cx.listener(Self::left))
+            .on_action(cx.listener(Self::right))
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::home))
+            .on_action(cx.listener(Self::end))
+            .on_action(cx.listener(Self::select_left))
+            .on_action(cx.listener(Self::select_right))
+            .on_action(cx.listener(Self::select_up))
+            .on_action(cx.listener(Self::select_down))
+            .on_action(cx.listener(Self::select_home))
+            .on_action(cx.listener(Self::select_end))
+            .on_action(cx.listener(Self::select_all))
+            .on_action(cx.listener(Self::copy))
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .w_full()
+            .px_3()
+            .py_2()
+            .rounded_md()
+            .border_1()
+            .border_color(if focused {
+                cx.theme().primary
+            } else {
+                cx.theme().border
+            })
+            .bg(cx.theme().background)
+            .when(self.disabled, |this| this.opacity(0.5))
+            .child(
+                div()
+                    .id("text-area-scroll")
+                    .track_scroll(&self.scroll_handle)
+                    .overflow_y_scroll()
+                    .overflow_x_hidden()
+                    .h(line_height * visible_rows as f32)
+                    .child(
+                        canvas(
+                            {
+                                let view = view.clone();
+                                move |bounds, cx| {
+                                    view.update(cx, |this, cx| {
+                                        let layout = this.layout_text(bounds, cx);
+                                        let cursor = focused
+                                            .then(|| {
+                                                this.point_for_offset(&layout, this.cursor_offset())
+                                            })
+                                            .flatten();
+                                        let selection = this.selection_bounds(&layout);
+                                        TextAreaPaint {
+                                            layout,
+                                            cursor,
+                                            selection,
+                                        }
+                                    })
+                                }
+                            },
+                            move |bounds, paint, cx| {
+                                if !disabled {
+                                    cx.handle_input(
+                                        &focus_handle,
+                                        ElementInputHandler::new(bounds, view),
+                                    );
+                                }
+
+                                for selection in paint.selection {
+                                    cx.paint_quad(fill(
+                                        Bounds::new(
+                                            bounds.origin + selection.origin,
+                                            selection.size,
+                                        ),
+                                        selection_color,
+                                    ));
+                                }
+
+                                let layout = paint.layout;
+                                let mut origin = bounds.origin;
+                                for line in &layout.lines {
+                                    line.paint(origin, layout.line_height, cx).ok();
+                                    origin.y += line.size(layout.line_height).height;
+                                }
+                                if let Some(cursor) = paint.cursor {
+                                    cx.paint_quad(fill(
+                                        Bounds::new(
+                                            bounds.origin + cursor,
+                                            size(px(1.5), layout.line_height),
+                                        ),
+                                        cursor_color,
+                                    ));
+                                }
+                            },
+                        )
+                        .w_full()
+                        .h(line_height * content_rows.max(visible_rows) as f32),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{TestAppContext, View, VisualTestContext};
+    use ti::theme::Theme;
+
+    use super::*;
+
+    fn build_text_area(
+        text: &'static str,
+        cx: &mut TestAppContext,
+    ) -> (View<TextArea>, &mut VisualTestContext) {
+        cx.update(|cx| Theme::init(cx));
+        let (text_area, cx) = cx.add_window_view(TextArea::new);
+        text_area.update(cx, |this, cx| this.set_text(text, cx));
+        (text_area, cx)
+    }
+
+    #[gpui::test]
+    fn test_move_across_lines(cx: &mut TestAppContext) {
+        let (text_area, cx) = build_text_area("ab\ncd", cx);
+        text_area.update(cx, |this, cx| {
+            assert_eq!(this.cursor_position(), (1, 2));
+
+            this.home(&Home, cx);
+            assert_eq!(this.cursor_offset(), 3);
+            assert_eq!(this.cursor_position(), (1, 0));
+
+            // Left and right step over the line break.
+            this.left(&Left, cx);
+            assert_eq!(this.cursor_offset(), 2);
+            assert_eq!(this.cursor_position(), (0, 2));
+            this.right(&Right, cx);
+            assert_eq!(this.cursor_position(), (1, 0));
+
+            this.move_to(0, cx);
+            this.end(&End, cx);
+            assert_eq!(this.cursor_position(), (0, 2));
+
+            // Left and right collapse a selection to its ends.
+            this.select_to(4, cx);
+            this.left(&Left, cx);
+            assert_eq!(this.selected_range, 2..2);
+            this.select_to(4, cx);
+            this.right(&Right, cx);
+            assert_eq!(this.selected_range, 4..4);
+        });
+    }
+
+    #[gpui::test]
+    fn test_select_to_reverses(cx: &mut TestAppContext) {
+        let (text_area, cx) = build_text_area("hello world", cx);
+        text_area.update(cx, |this, cx| {
+            this.move_to(5, cx);
+            this.select_to(8, cx);
+            assert_eq!(this.selected_range, 5..8);
+            assert!(!this.selection_reversed);
+            assert_eq!(this.selected_text(), " wo");
+
+            // The head passes the anchor, which stays in place.
+            this.select_to(2, cx);
+            assert_eq!(this.selected_range, 2..5);
+            assert!(this.selection_reversed);
+            assert_eq!(this.cursor_offset(), 2);
+
+            this.select_to(7, cx);
+            assert_eq!(this.selected_range, 5..7);
+            assert!(!this.selection_reversed);
+
+            this.move_to(5, cx);
+            this.select_left(&SelectLeft, cx);
+            assert_eq!(this.selected_range, 4..5);
+            assert!(this.selection_reversed);
+
+            this.select_all(&SelectAll, cx);
+            assert_eq!(this.selected_text(), "hello world");
+        });
+    }
+
+    #[gpui::test]
+    fn test_replace_text_with_marked_text(cx: &mut TestAppContext) {
+        let (text_area, cx) = build_text_area("ab", cx);
+        text_area.update(cx, |this, cx| {
+            // The IME composes a pinyin, then its character, then commits it.
+            this.replace_and_mark_text_in_range(None, "ni", None, cx);
+            assert_eq!(this.text, "abni");
+            assert_eq!(this.marked_range, Some(2..4));
+            assert_eq!(this.selected_range, 4..4);
+
+            this.replace_and_mark_text_in_range(None, "\u{4f60}", Some(0..1), cx);
+            assert_eq!(this.text, "ab\u{4f60}");
+            assert_eq!(this.marked_range, Some(2..5));
+            assert_eq!(this.selected_range, 2..5);
+            assert_eq!(this.marked_text_range(cx), Some(2..3));
+
+            this.replace_text_in_range(None, "\u{4f60}\u{597d}", cx);
+            assert_eq!(this.text, "ab\u{4f60}\u{597d}");
+            assert_eq!(this.marked_range, None);
+            assert_eq!(this.selected_range, 8..8);
+
+            // An explicit range is in UTF-16 units.
+            this.replace_text_in_range(Some(2..3), "x", cx);
+            assert_eq!(this.text, "abx\u{597d}");
+            assert_eq!(this.text_for_range(3..4, cx).as_deref(), Some("\u{597d}"));
+        });
+    }
+
+    #[gpui::test]
+    fn test_cut_and_paste(cx: &mut TestAppContext) {
+        let (text_area, cx) = build_text_area("hello world", cx);
+        text_area.update(cx, |this, cx| {
+            this.move_to(0, cx);
+            this.select_to(5, cx);
+            this.cut(&Cut, cx);
+            assert_eq!(this.text, " world");
+            assert_eq!(
+                cx.read_from_clipboard().map(|item| item.text().clone()),
+                Some("hello".to_string())
+            );
+
+            this.end(&End, cx);
+            this.paste(&Paste, cx);
+            assert_eq!(this.text, " worldhello");
+
+            // Pasting replaces the selection, with Windows line breaks normalized.
+            cx.write_to_clipboard(ClipboardItem::new("a\r\nb".to_string()));
+            this.select_all(&SelectAll, cx);
+            this.paste(&Paste, cx);
+            assert_eq!(this.text, "a\nb");
+
+            // Copying without a selection keeps the clipboard.
+            this.copy(&Copy, cx);
+            assert_eq!(
+                cx.read_from_clipboard().map(|item| item.text().clone()),
+                Some("a\r\nb".to_string())
+            );
+        });
+    }
+
+    #[test]
+    fn test_utf16_offsets() {
+        let text = "a\u{e9}\u{4e2d}\u{1f600}b";
+        // Bytes: a=1, é=2, 中=3, 😀=4; UTF-16 units: 1, 1, 1, 2.
+        assert_eq!(offset_to_utf16(text, 0), 0);
+        assert_eq!(offset_to_utf16(text, 3), 2);
+        assert_eq!(offset_to_utf16(text, 6), 3);
+        assert_eq!(offset_to_utf16(text, 10), 5);
+        assert_eq!(offset_to_utf16(text, text.len()), 6);
+
+        assert_eq!(offset_from_utf16(text, 2), 3);
+        assert_eq!(offset_from_utf16(text, 5), 10);
+        assert_eq!(offset_from_utf16(text, 6), text.len());
+        assert_eq!(offset_from_utf16(text, 100), text.len());
+
+        let range = 1..10;
+        assert_eq!(range_from_utf16(text, &range_to_utf16(text, &range)), range);
+    }
+}
@@ -0,0 +1,357 @@
+use chrono::{NaiveTime, Timelike as _};
+use gpui::{
+    actions, deferred, div, prelude::FluentBuilder as _, px, svg, AppContext, ElementId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    MouseButton, ParentElement as _, Render, SharedString, StatefulInteractiveElement as _,
+    Styled as _, Subscription, View, ViewContext, VisualContext as _,
+};
+use ti::{
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme,
+    v_flex,
+};
+
+actions!(time_picker, [Cancel]);
+
+const CONTEXT: &str = "TimePicker";
+const COLUMN_HEIGHT: f32 = 200.;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("escape", Cancel, Some(CONTEXT))]);
+}
+
+/// Whether the current `ti` locale uses a 12-hour clock with AM/PM.
+fn locale_uses_12_hour() -> bool {
+    let locale = ti::locale().to_string();
+    let mut parts = locale.split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    let region = parts.next().unwrap_or_default();
+    match language {
+        "en" => matches!(region, "" | "US" | "CA" | "AU" | "NZ" | "IN" | "PH"),
+        "ko" | "hi" => true,
+        _ => false,
+    }
+}
+
+/// Format a time as `2:30 PM` with a 12-hour clock, otherwise as `14:30`.
+pub fn format_time(time: NaiveTime, hour_12: bool) -> String {
+    if hour_12 {
+        time.format("%-I:%M %p").to_string()
+    } else {
+        time.format("%H:%M").to_string()
+    }
+}
+
+/// Parse a time written with a 24-hour clock, or a 12-hour clock with AM/PM.
+pub fn parse_time(text: &str) -> Option<NaiveTime> {
+    let text = text.trim().to_uppercase();
+    ["%H:%M", "%H:%M:%S", "%I:%M %p", "%I:%M%p"]
+        .into_iter()
+        .find_map(|format| NaiveTime::parse_from_str(&text, format).ok())
+}
+
+pub enum TimePickerEvent {
+    /// The time changed, by typing or picking it.
+    Change(Option<NaiveTime>),
+}
+
+/// A time input with columns to pick the hour, the minutes and AM/PM.
+///
+/// The clock follows the `ti` locale unless set with [`TimePicker::hour_12`].
+pub struct TimePicker {
+    input: View<TextInput>,
+    value: Option<NaiveTime>,
+    hour_12: bool,
+    /// The interval of the minutes listed in the popup.
+    minute_step: u32,
+    open: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TimePicker {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let hour_12 = locale_uses_12_hour();
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx).placeholder(if hour_12 { "hh:mm AM" } else { "hh:mm" })
+        });
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+
+        Self {
+            input,
+            value: None,
+            hour_12,
+            minute_step: 5,
+            open: false,
+            _subscriptions,
+        }
+    }
+
+    /// Use a 12-hour clock with AM/PM, or a 24-hour clock.
+    pub fn hour_12(mut self, hour_12: bool) -> Self {
+        self.hour_12 = hour_12;
+        self
+    }
+
+    /// List the minutes by this interval in the popup, 5 by default. Any minute can be typed.
+    pub fn minute_step(mut self, step: u32) -> Self {
+        self.minute_step = step.clamp(1, 30);
+        self
+    }
+
+    pub fn value(&self) -> Option<NaiveTime> {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: Option<NaiveTime>, cx: &mut ViewContext<Self>) {
+        self.value = value;
+        let text = value
+            .map(|time| format_time(time, self.hour_12))
+            .unwrap_or_default();
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.input
+            .update(cx, |input, cx| input.set_disabled(disabled, cx));
+        if disabled {
+            self.close(cx);
+        }
+    }
+
+    fn change(&mut self, value: Option<NaiveTime>, cx: &mut ViewContext<Self>) {
+        if value != self.value {
+            self.set_value(value, cx);
+            cx.emit(TimePickerEvent::Change(value));
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::PressEnter | InputEvent::Blur => {
+                let text = input.read(cx).text();
+                if text.trim().is_empty() {
+                    self.change(None, cx);
+                } else if let Some(time) = parse_time(&text) {
+                    self.change(Some(time), cx);
+                } else {
+                    // Restore the last valid value.
+                    self.set_value(self.value, cx);
+                }
+            }
+            InputEvent::Change(_) | InputEvent::Focus => {}
+        }
+    }
+
+    fn open(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = true;
+        cx.notify();
+    }
+
+    fn close(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        cx.notify();
+    }
+
+    fn cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        self.close(cx);
+    }
+
+    /// The hour from 0 to 23 and the minute of the value, noon without a value.
+    fn hour_minute(&self) -> (u32, u32) {
+        self.value
+            .map_or((12, 0), |time| (time.hour(), time.minute()))
+    }
+
+    fn set_hour_minute(&mut self, hour: u32, minute: u32, cx: &mut ViewContext<Self>) {
+        self.change(NaiveTime::from_hms_opt(hour, minute, 0), cx);
+    }
+
+    fn render_column(
+        &self,
+        id: &'static str,
+        items: Vec<(SharedString, bool, u32)>,
+        on_pick: fn(&mut Self, u32, &mut ViewContext<Self>),
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        v_flex()
+            .id(id)
+            .h(px(COLUMN_HEIGHT))
+            .px_1()
+            .overflow_y_scroll()
+            .children(items.into_iter().map(|(label, selected, value)| {
+                div()
+                    .id(ElementId::NamedInteger(id.into(), value as usize))
+                    .flex_none()
+                    .px_3()
+                    .py_1()
+                    .rounded_md()
+                    .text_sm()
+                    .cursor_pointer()
+                    .map(|this| {
+                        if selected {
+                            this.bg(cx.theme().primary)
+                                .text_color(cx.theme().background)
+                        } else {
+                            this.hover(|this| this.bg(cx.theme().list_hover))
+                        }
+                    })
+                    .child(label)
+                    .on_click(cx.listener(move |this, _, cx| on_pick(this, value, cx)))
+            }))
+    }
+
+    fn render_popup(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (hour, minute) = self.hour_minute();
+        let pm = hour >= 12;
+
+        let hours: Vec<(SharedString, bool, u32)> = if self.hour_12 {
+            // 12 first, as the first hour of the morning and the afternoon.
+            [12].into_iter()
+                .chain(1..12)
+                .map(|h| (h.to_string().into(), hour % 12 == h % 12, h % 12))
+                .collect()
+        } else {
+            (0..24)
+                .map(|h| (format!("{:02}", h).into(), hour == h, h))
+                .collect()
+        };
+        let minutes: Vec<(SharedString, bool, u32)> = (0..60)
+            .step_by(self.minute_step as usize)
+            .map(|m| (format!("{:02}", m).into(), minute == m, m))
+            .collect();
+
+        h_flex()
+            .items_start()
+            .child(self.render_column(
+                "hours",
+                hours,
+                |this, h, cx| {
+                    let (hour, minute) = this.hour_minute();
+                    let hour = if this.hour_12 { h + hour / 12 * 12 } else { h };
+                    this.set_hour_minute(hour, minute, cx);
+                },
+                cx,
+            ))
+            .child(self.render_column(
+                "minutes",
+                minutes,
+                |this, m, cx| {
+                    let (hour, _) = this.hour_minute();
+                    this.set_hour_minute(hour, m, cx);
+                },
+                cx,
+            ))
+            .when(self.hour_12, |this| {
+                this.child(self.render_column(
+                    "period",
+                    vec![("AM".into(), !pm, 0), ("PM".into(), pm, 12)],
+                    |this, offset, cx| {
+                        let (hour, minute) = this.hour_minute();
+                        this.set_hour_minute(hour % 12 + offset, minute, cx);
+                    },
+                    cx,
+                ))
+            })
+    }
+}
+
+impl EventEmitter<TimePickerEvent> for TimePicker {}
+
+impl FocusableView for TimePicker {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for TimePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let id = cx.view().entity_id().as_u64() as usize;
+
+        div()
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::cancel))
+            .relative()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(div().flex_1().child(self.input.clone()))
+                    .child(
+                        div()
+                            .id(ElementId::NamedInteger("clock".into(), id))
+                            .p_1()
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|this| this.bg(cx.theme().list_hover))
+                            .child(
+                                svg()
+                                    .path("icons/chevron-down.svg")
+                                    .size_4()
+                                    .text_color(cx.theme().muted_foreground),
+                            )
+                            .on_click(cx.listener(|this, _, cx| this.open(cx))),
+                    ),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        div()
+                            .absolute()
+                            .top_full()
+                            .left_0()
+                            .mt_1()
+                            .p_1()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .bg(cx.theme().background)
+                            .shadow_md()
+                            .on_mouse_down_out(cx.listener(|this, _, cx| this.close(cx)))
+                            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                            .child(self.render_popup(cx)),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(parse_time("14:30"), Some(time(14, 30)));
+        assert_eq!(parse_time(" 9:05 "), Some(time(9, 5)));
+        assert_eq!(parse_time("14:30:15"), NaiveTime::from_hms_opt(14, 30, 15));
+        assert_eq!(parse_time("2:30 PM"), Some(time(14, 30)));
+        assert_eq!(parse_time("2:30pm"), Some(time(14, 30)));
+        assert_eq!(parse_time("12:00 am"), Some(time(0, 0)));
+        assert_eq!(parse_time("12:00 PM"), Some(time(12, 0)));
+        assert_eq!(parse_time("24:00"), None);
+        assert_eq!(parse_time("13:00 PM"), None);
+        assert_eq!(parse_time(""), None);
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(time(14, 30), false), "14:30");
+        assert_eq!(format_time(time(14, 30), true), "2:30 PM");
+        assert_eq!(format_time(time(0, 5), true), "12:05 AM");
+        for hour_12 in [false, true] {
+            let text = format_time(time(23, 59), hour_12);
+            assert_eq!(parse_time(&text), Some(time(23, 59)));
+        }
+    }
+}
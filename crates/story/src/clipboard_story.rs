@@ -0,0 +1,153 @@
+use components::clipboard::{self, Clipboard, ClipboardContent, ClipboardHistory};
+use gpui::{
+    div, prelude::FluentBuilder as _, InteractiveElement as _, IntoElement, ParentElement as _,
+    Render, SharedString, StatefulInteractiveElement as _, Styled, View, ViewContext,
+    VisualContext, WindowContext,
+};
+use serde_json::json;
+use ti::{button::Button, h_flex, label::Label, theme::ActiveTheme, v_flex, Sizable as _};
+
+use crate::section;
+
+pub struct ClipboardStory {
+    buttons: Vec<(SharedString, View<Clipboard>)>,
+    pasted: Option<ClipboardContent>,
+}
+
+impl ClipboardStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let contents: [(SharedString, ClipboardContent); 3] = [
+            ("cargo add ti".into(), "cargo add ti".into()),
+            (
+                "Rich text with bold".into(),
+                ClipboardContent::Html {
+                    html: "Rich text with <b>bold</b>".into(),
+                    text: "Rich text with bold".into(),
+                },
+            ),
+            (
+                "{ \"name\": \"ti\", \"stars\": 42 }".into(),
+                json!({ "name": "ti", "stars": 42 }).into(),
+            ),
+        ];
+        let buttons = contents
+            .into_iter()
+            .map(|(preview, content)| {
+                let button = cx.new_view(|_| Clipboard::new(content).label("Copy"));
+                (preview, button)
+            })
+            .collect();
+
+        // Show the history as it changes, including copies from other views.
+        cx.observe_global::<ClipboardHistory>(|_, cx| cx.notify())
+            .detach();
+
+        Self {
+            buttons,
+            pasted: None,
+        }
+    }
+
+    fn paste(&mut self, cx: &mut ViewContext<Self>) {
+        self.pasted = clipboard::paste(cx);
+        cx.notify();
+    }
+
+    fn render_history(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entries = clipboard::history(cx)
+            .entries()
+            .map(|entry| (entry.kind(), entry.text()))
+            .collect::<Vec<_>>();
+
+        v_flex()
+            .w_full()
+            .gap_1()
+            .when(entries.is_empty(), |this| {
+                this.child(
+                    Label::new("Copy something to fill the history.")
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+            .children(entries.into_iter().enumerate().map(|(ix, (kind, text))| {
+                h_flex()
+                    .id(ix)
+                    .gap_2()
+                    .px_2()
+                    .py_1()
+                    .rounded_md()
+                    .cursor_pointer()
+                    .hover(|this| this.bg(cx.theme().list_hover))
+                    .child(
+                        div()
+                            .w_12()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(kind),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .overflow_x_hidden()
+                            .whitespace_nowrap()
+                            .child(text.replace('\n', " ")),
+                    )
+                    .on_click(cx.listener(move |_, _, cx| {
+                        cx.update_global::<ClipboardHistory, _>(|history, cx| {
+                            history.restore(ix, cx)
+                        });
+                    }))
+            }))
+    }
+}
+
+impl Render for ClipboardStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .child(
+                section("Copy button", cx).child(v_flex().w_full().gap_2().children(
+                    self.buttons.iter().map(|(preview, button)| {
+                        h_flex()
+                            .gap_3()
+                            .items_center()
+                            .child(div().text_sm().child(preview.clone()))
+                            .child(button.clone())
+                    }),
+                )),
+            )
+            .child(
+                section("Paste", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_2()
+                        .child(
+                            Button::new("paste", cx)
+                                .label("Paste")
+                                .small()
+                                .on_click(cx.listener(|this, _, cx| this.paste(cx))),
+                        )
+                        .child(match &self.pasted {
+                            Some(content) => format!("{}: {}", content.kind(), content.text()),
+                            None => "Nothing pasted yet.".to_string(),
+                        }),
+                ),
+            )
+            .child(
+                section("History", cx).child(self.render_history(cx)).child(
+                    Button::new("clear-history", cx)
+                        .label("Clear")
+                        .small()
+                        .on_click(cx.listener(|_, _, cx| {
+                            cx.update_global::<ClipboardHistory, _>(|history, _| history.clear());
+                        })),
+                ),
+            )
+    }
+}
@@ -0,0 +1,138 @@
+use std::{sync::Arc, time::Duration};
+
+use components::combo_box::{ComboBox, ComboBoxDelegate, ComboBoxEvent, Suggestion};
+use gpui::{
+    AppContext, IntoElement, ParentElement as _, Render, SharedString, Styled, Task, Timer, View,
+    ViewContext, VisualContext, WindowContext,
+};
+use ti::{h_flex, v_flex};
+
+use crate::{
+    list_story::{random_company, Company},
+    section,
+};
+
+/// Searches the fake companies in the background, with some latency like a remote source.
+struct CompanySource {
+    companies: Arc<Vec<Company>>,
+    by_industry: bool,
+}
+
+impl ComboBoxDelegate for CompanySource {
+    fn search(&self, query: &str, cx: &mut AppContext) -> Task<Vec<Suggestion>> {
+        let companies = self.companies.clone();
+        let by_industry = self.by_industry;
+        let query = query.trim().to_string();
+        cx.background_executor().spawn(async move {
+            Timer::after(Duration::from_millis(100)).await;
+            if query.is_empty() {
+                return Vec::new();
+            }
+
+            let mut suggestions = Vec::new();
+            for company in companies.iter() {
+                let suggestion = if by_industry {
+                    if suggestions
+                        .iter()
+                        .any(|s: &Suggestion| s.label.as_ref() == company.industry)
+                    {
+                        continue;
+                    }
+                    Suggestion::matching(company.industry.clone(), &query)
+                } else {
+                    Suggestion::matching(company.name.clone(), &query)
+                        .map(|s| s.detail(company.industry.clone()))
+                };
+                suggestions.extend(suggestion);
+                if suggestions.len() == 50 {
+                    break;
+                }
+            }
+            suggestions
+        })
+    }
+}
+
+pub struct ComboBoxStory {
+    company: View<ComboBox<CompanySource>>,
+    industry: View<ComboBox<CompanySource>>,
+    company_value: Option<SharedString>,
+    industry_value: Option<SharedString>,
+}
+
+impl ComboBoxStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let companies = Arc::new((0..1_000).map(|_| random_company()).collect::<Vec<_>>());
+
+        let company = cx.new_view(|cx| {
+            ComboBox::new(
+                CompanySource {
+                    companies: companies.clone(),
+                    by_industry: false,
+                },
+                cx,
+            )
+        });
+        let industry = cx.new_view(|cx| {
+            ComboBox::new(
+                CompanySource {
+                    companies,
+                    by_industry: true,
+                },
+                cx,
+            )
+            .must_match(true)
+            .debounce(Duration::from_millis(300))
+        });
+
+        cx.subscribe(&company, |this, _, event: &ComboBoxEvent, cx| {
+            let ComboBoxEvent::Confirm(value) = event;
+            this.company_value = Some(value.clone());
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&industry, |this, _, event: &ComboBoxEvent, cx| {
+            let ComboBoxEvent::Confirm(value) = event;
+            this.industry_value = Some(value.clone());
+            cx.notify();
+        })
+        .detach();
+
+        Self {
+            company,
+            industry,
+            company_value: None,
+            industry_value: None,
+        }
+    }
+}
+
+impl Render for ComboBoxStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().size_full().p_4().gap_3().child(
+            h_flex()
+                .gap_3()
+                .items_start()
+                .child(
+                    section("Company, free text", cx)
+                        .child(self.company.clone())
+                        .child(format!(
+                            "Value: {}",
+                            self.company_value.clone().unwrap_or_else(|| "None".into())
+                        )),
+                )
+                .child(
+                    section("Industry, must match", cx)
+                        .child(self.industry.clone())
+                        .child(format!(
+                            "Value: {}",
+                            self.industry_value.clone().unwrap_or_else(|| "None".into())
+                        )),
+                ),
+        )
+    }
+}
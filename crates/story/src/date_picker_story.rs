@@ -0,0 +1,101 @@
+use chrono::{Days, Local};
+use components::date_picker::{format_date, DatePicker, DatePickerEvent, DateSelection};
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, Styled, View, ViewContext, VisualContext,
+    WindowContext,
+};
+use ti::{h_flex, v_flex};
+
+use crate::section;
+
+fn format_selection(selection: Option<DateSelection>) -> String {
+    match selection {
+        Some(DateSelection::Single(date)) => format_date(date),
+        Some(DateSelection::Range(start, end)) => {
+            format!(
+                "{} to {}, {} days",
+                format_date(start),
+                format_date(end),
+                (end - start).num_days() + 1
+            )
+        }
+        None => "None".to_string(),
+    }
+}
+
+pub struct DatePickerStory {
+    date: View<DatePicker>,
+    range: View<DatePicker>,
+    disabled: View<DatePicker>,
+    date_value: Option<DateSelection>,
+    range_value: Option<DateSelection>,
+}
+
+impl DatePickerStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let today = Local::now().date_naive();
+        let date = cx.new_view(DatePicker::new);
+        let range = cx.new_view(|cx| {
+            let mut picker = DatePicker::new(cx).range(cx);
+            let end = today.checked_add_days(Days::new(6)).unwrap_or(today);
+            picker.set_value(Some(DateSelection::Range(today, end)), cx);
+            picker
+        });
+        let disabled = cx.new_view(|cx| {
+            let mut picker = DatePicker::new(cx);
+            picker.set_value(Some(DateSelection::Single(today)), cx);
+            picker.set_disabled(true, cx);
+            picker
+        });
+
+        cx.subscribe(&date, |this, _, event: &DatePickerEvent, cx| {
+            let DatePickerEvent::Change(value) = event;
+            this.date_value = *value;
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&range, |this, _, event: &DatePickerEvent, cx| {
+            let DatePickerEvent::Change(value) = event;
+            this.range_value = *value;
+            cx.notify();
+        })
+        .detach();
+
+        let range_value = range.read(cx).value();
+        Self {
+            date,
+            range,
+            disabled,
+            date_value: None,
+            range_value,
+        }
+    }
+}
+
+impl Render for DatePickerStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().size_full().p_4().gap_3().child(
+            h_flex()
+                .gap_3()
+                .items_start()
+                .child(
+                    section("Date", cx)
+                        .child(v_flex().w(px(220.)).child(self.date.clone()))
+                        .child(format!("Value: {}", format_selection(self.date_value))),
+                )
+                .child(
+                    section("Range", cx)
+                        .child(v_flex().w(px(280.)).child(self.range.clone()))
+                        .child(format!("Value: {}", format_selection(self.range_value))),
+                )
+                .child(
+                    section("Disabled", cx)
+                        .child(v_flex().w(px(220.)).child(self.disabled.clone())),
+                ),
+        )
+    }
+}
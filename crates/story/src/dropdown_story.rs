@@ -0,0 +1,156 @@
+use components::dropdown::{Dropdown, DropdownDelegate, DropdownEvent};
+use gpui::{
+    IntoElement, ParentElement as _, Render, SharedString, Styled, View, ViewContext,
+    VisualContext, WindowContext,
+};
+use ti::{h_flex, v_flex};
+
+use crate::{
+    list_story::{random_company, Company},
+    section,
+};
+
+/// Lists the companies by name, searched by name or industry.
+struct CompanyItems(Vec<Company>);
+
+impl DropdownDelegate for CompanyItems {
+    fn items_count(&self) -> usize {
+        self.0.len()
+    }
+
+    fn label(&self, ix: usize) -> SharedString {
+        self.0[ix].name.clone().into()
+    }
+
+    fn matches(&self, ix: usize, query: &str) -> bool {
+        let company = &self.0[ix];
+        let query = query.to_lowercase();
+        company.name.to_lowercase().contains(&query)
+            || company.industry.to_lowercase().contains(&query)
+    }
+}
+
+pub struct DropdownStory {
+    fruit: View<Dropdown<Vec<SharedString>>>,
+    company: View<Dropdown<CompanyItems>>,
+    tags: View<Dropdown<Vec<SharedString>>>,
+    fruit_value: Option<SharedString>,
+    company_value: Option<SharedString>,
+    tags_value: Vec<SharedString>,
+}
+
+impl DropdownStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let fruits = ["Apple", "Banana", "Cherry", "Grape", "Orange", "Pear"]
+            .into_iter()
+            .map(SharedString::from)
+            .collect::<Vec<_>>();
+        let fruit = cx.new_view(|cx| {
+            Dropdown::new(fruits, cx)
+                .placeholder("Select a fruit")
+                .cleanable()
+        });
+
+        let companies = (0..200).map(|_| random_company()).collect();
+        let company = cx.new_view(|cx| {
+            Dropdown::new(CompanyItems(companies), cx)
+                .searchable(cx)
+                .placeholder("Select a company")
+        });
+
+        let tags = [
+            "Bug",
+            "Feature",
+            "Docs",
+            "Performance",
+            "Question",
+            "Design",
+        ]
+        .into_iter()
+        .map(SharedString::from)
+        .collect::<Vec<_>>();
+        let tags = cx.new_view(|cx| {
+            let mut dropdown = Dropdown::new(tags, cx)
+                .placeholder("Add tags")
+                .multiple()
+                .cleanable();
+            dropdown.set_selected_indices(vec![1, 3], cx);
+            dropdown
+        });
+
+        cx.subscribe(&fruit, |this, fruit, event: &DropdownEvent, cx| {
+            let DropdownEvent::Change(selected) = event;
+            this.fruit_value = selected
+                .first()
+                .map(|ix| fruit.read(cx).delegate().label(*ix));
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&company, |this, company, event: &DropdownEvent, cx| {
+            let DropdownEvent::Change(selected) = event;
+            this.company_value = selected
+                .first()
+                .map(|ix| company.read(cx).delegate().label(*ix));
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&tags, |this, tags, event: &DropdownEvent, cx| {
+            let DropdownEvent::Change(selected) = event;
+            this.tags_value = selected
+                .iter()
+                .map(|ix| tags.read(cx).delegate().label(*ix))
+                .collect();
+            cx.notify();
+        })
+        .detach();
+
+        let tags_value = tags
+            .read(cx)
+            .selected_indices()
+            .iter()
+            .map(|ix| tags.read(cx).delegate().label(*ix))
+            .collect();
+
+        Self {
+            fruit,
+            company,
+            tags,
+            fruit_value: None,
+            company_value: None,
+            tags_value,
+        }
+    }
+}
+
+impl Render for DropdownStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let value = |value: &Option<SharedString>| {
+            format!("Value: {}", value.clone().unwrap_or_else(|| "None".into()))
+        };
+
+        v_flex().size_full().p_4().gap_3().child(
+            h_flex()
+                .gap_3()
+                .items_start()
+                .child(
+                    section("Cleanable", cx)
+                        .child(self.fruit.clone())
+                        .child(value(&self.fruit_value)),
+                )
+                .child(
+                    section("Searchable", cx)
+                        .child(self.company.clone())
+                        .child(value(&self.company_value)),
+                )
+                .child(
+                    section("Multiple", cx)
+                        .child(self.tags.clone())
+                        .child(format!("Value: {}", self.tags_value.join(", "))),
+                ),
+        )
+    }
+}
@@ -1,10 +1,13 @@
+use components::otp_input::{OtpEvent, OtpInput};
 use gpui::{
-    actions, div, AppContext, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
-    ParentElement as _, Render, Styled, View, ViewContext, VisualContext, WindowContext,
+    actions, div, prelude::FluentBuilder as _, px, AppContext, FocusHandle, InteractiveElement,
+    IntoElement, KeyBinding, ParentElement as _, Render, SharedString, Styled, View, ViewContext,
+    VisualContext, WindowContext,
 };
 
 use ti::{
     button::Button,
+    checkbox::Checkbox,
     h_flex,
     input::{InputEvent, TextInput},
     v_flex, FocusableCycle, IconName, Sizable,
@@ -33,6 +36,12 @@ pub struct InputStory {
     both_input1: View<TextInput>,
     large_input: View<TextInput>,
     small_input: View<TextInput>,
+    otp_masked: bool,
+    otp_input: View<OtpInput>,
+    otp_value: Option<SharedString>,
+    otp_input_small: View<OtpInput>,
+    otp_input_large: View<OtpInput>,
+    opt_input_sized: View<OtpInput>,
 }
 
 impl InputStory {
@@ -84,6 +93,16 @@ impl InputStory {
                 .placeholder("This input have prefix and suffix.")
         });
 
+        let otp_input = cx.new_view(|cx| OtpInput::new(6, cx).masked(true));
+        cx.subscribe(&otp_input, |this, _, event: &OtpEvent, cx| {
+            this.otp_value = match event {
+                OtpEvent::Change(_) => None,
+                OtpEvent::Complete(value) => Some(value.clone()),
+            };
+            cx.notify();
+        })
+        .detach();
+
         Self {
             input1,
             input2,
@@ -104,32 +123,30 @@ impl InputStory {
             prefix_input1,
             suffix_input1,
             both_input1,
-            /*
-                        otp_masked: true,
-                        otp_input,
-                        otp_value: None,
-                        otp_input_small: cx.new_view(|cx| {
-                            OtpInput::new(6, cx)
-                                .default_value("123456")
-                                .masked(true)
-                                .small()
-                                .groups(1)
-                        }),
-                        otp_input_large: cx.new_view(|cx| {
-                            OtpInput::new(6, cx)
-                                .groups(3)
-                                .large()
-                                .default_value("012345")
-                                .masked(true)
-                        }),
-                        opt_input_sized: cx.new_view(|cx| {
-                            OtpInput::new(4, cx)
-                                .groups(1)
-                                .masked(true)
-                                .default_value("654321")
-                                .with_size(px(55.))
-                        }),
-            */
+            otp_masked: true,
+            otp_input,
+            otp_value: None,
+            otp_input_small: cx.new_view(|cx| {
+                OtpInput::new(6, cx)
+                    .default_value("123456")
+                    .masked(true)
+                    .small()
+                    .groups(1)
+            }),
+            otp_input_large: cx.new_view(|cx| {
+                OtpInput::new(6, cx)
+                    .groups(3)
+                    .large()
+                    .default_value("012345")
+                    .masked(true)
+            }),
+            opt_input_sized: cx.new_view(|cx| {
+                OtpInput::new(4, cx)
+                    .groups(1)
+                    .masked(true)
+                    .default_value("654321")
+                    .with_size(px(55.))
+            }),
         }
     }
 
@@ -141,6 +158,18 @@ impl InputStory {
         self.cycle_focus(false, cx);
     }
 
+    fn toggle_opt_masked(&mut self, _: &bool, cx: &mut ViewContext<Self>) {
+        self.otp_masked = !self.otp_masked;
+        for input in [
+            &self.otp_input,
+            &self.otp_input_small,
+            &self.otp_input_large,
+            &self.opt_input_sized,
+        ] {
+            input.update(cx, |input, cx| input.set_masked(self.otp_masked, cx));
+        }
+    }
+
     fn on_input_event(
         &mut self,
         _: View<TextInput>,
@@ -168,6 +197,10 @@ impl FocusableCycle for InputStory {
             self.suffix_input1.focus_handle(cx),
             self.large_input.focus_handle(cx),
             self.small_input.focus_handle(cx),
+            self.otp_input_small.focus_handle(cx),
+            self.otp_input.focus_handle(cx),
+            self.otp_input_large.focus_handle(cx),
+            self.opt_input_sized.focus_handle(cx),
         ]
         .to_vec()
     }
@@ -214,7 +247,6 @@ impl Render for InputStory {
                             .child(self.small_input.clone()),
                     ),
             )
-            /*
             .child(
                 section(
                     h_flex()
@@ -241,7 +273,6 @@ impl Render for InputStory {
                         .child(self.opt_input_sized.clone()),
                 ),
             )
-            */
             .child(
                 h_flex()
                     .items_center()
@@ -1,18 +1,42 @@
 mod button_story;
+mod clipboard_story;
+mod combo_box_story;
+mod date_picker_story;
+mod dropdown_story;
 mod icon_story;
 mod input_story;
 mod list_story;
+mod number_input_story;
 mod popup_story;
+mod progress_story;
+mod registry;
 mod scrollable_story;
+mod slider_story;
+mod story_navigator;
 mod switch_story;
+mod table_story;
+mod text_area_story;
+mod time_picker_story;
 
 pub use button_story::ButtonStory;
+pub use clipboard_story::ClipboardStory;
+pub use combo_box_story::ComboBoxStory;
+pub use date_picker_story::DatePickerStory;
+pub use dropdown_story::DropdownStory;
 pub use icon_story::IconStory;
 pub use input_story::InputStory;
 pub use list_story::ListStory;
+pub use number_input_story::NumberInputStory;
 pub use popup_story::PopupStory;
+pub use progress_story::ProgressStory;
+pub use registry::{open_story, RegisterStories, StoryEntry, StoryRegistry};
 pub use scrollable_story::ScrollableStory;
+pub use slider_story::SliderStory;
+pub use story_navigator::StoryNavigator;
 pub use switch_story::SwitchStory;
+pub use table_story::TableStory;
+pub use text_area_story::TextAreaStory;
+pub use time_picker_story::TimePickerStory;
 
 use gpui::{
     div, prelude::FluentBuilder as _, px, AnyElement, AnyView, AppContext, Div, EventEmitter,
@@ -31,6 +55,119 @@ use ti::{divider::Divider, h_flex, label::Label, v_flex};
 pub fn init(cx: &mut AppContext) {
     input_story::init(cx);
     popup_story::init(cx);
+    switch_story::init(cx);
+}
+
+/// The registration hook of the stories of this crate.
+pub fn register_stories(registry: &mut StoryRegistry) {
+    for story in stories() {
+        registry.add(story);
+    }
+}
+
+/// The stories of this crate, added by [`register_stories`].
+pub fn stories() -> Vec<StoryEntry> {
+    vec![
+        StoryEntry::new(
+            "Buttons",
+            "Displays a button or a component that looks like a button.",
+            "Basic",
+            |cx| ButtonStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Input",
+            "A control that allows the user to input text.",
+            "Form",
+            |cx| InputStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Switch",
+            "A control that allows the user to toggle between two states.",
+            "Form",
+            |cx| SwitchStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Text Area",
+            "A multi-line text input with soft wrap and auto grow.",
+            "Form",
+            |cx| TextAreaStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Number Input",
+            "A numeric input with a stepper, a range and locale-aware formatting.",
+            "Form",
+            |cx| NumberInputStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Combo Box",
+            "A text input with suggestions that follow the typed text.",
+            "Form",
+            |cx| ComboBoxStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Dropdown",
+            "A button that opens a list to pick one or several values from.",
+            "Form",
+            |cx| DropdownStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Slider",
+            "Pick a value or a range by dragging thumbs along a track.",
+            "Form",
+            |cx| SliderStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Date Picker",
+            "Type a date, or pick a date or a range in a calendar.",
+            "Form",
+            |cx| DatePickerStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Time Picker",
+            "Type a time, or pick the hour and the minutes.",
+            "Form",
+            |cx| TimePickerStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Popup",
+            "A popup displays content on top of the main page.",
+            "Overlay",
+            |cx| PopupStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Clipboard",
+            "Copy typed contents to the clipboard, and paste them from its history.",
+            "Basic",
+            |cx| ClipboardStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "List",
+            "A list displays a series of items.",
+            "Data Display",
+            |cx| ListStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Table",
+            "A virtualized table with resizable, reorderable and sortable columns.",
+            "Data Display",
+            |cx| TableStory::view(cx).into(),
+        ),
+        StoryEntry::new(
+            "Progress",
+            "Progress bars, spinners and skeletons shown while content loads.",
+            "Data Display",
+            |cx| ProgressStory::view(cx).into(),
+        ),
+        StoryEntry::new("Icon", "Icon use examples", "Basic", |cx| {
+            IconStory::view(cx).into()
+        }),
+        StoryEntry::new(
+            "Scrollable",
+            "A scrollable area with scroll bar.",
+            "Layout",
+            |cx| ScrollableStory::view(cx).into(),
+        ),
+    ]
 }
 
 pub fn section(title: impl IntoElement, cx: &WindowContext) -> Div {
@@ -83,6 +220,10 @@ impl Item for StoryContainer {
         Label::new(self.name.clone()).into_any_element()
     }
 
+    fn tab_title(&self, _: &AppContext) -> Option<SharedString> {
+        Some(self.name.clone())
+    }
+
     fn deactivated(&mut self, _cx: &mut ViewContext<Self>) {
         self.active = false;
     }
@@ -165,6 +306,10 @@ impl StoryContainer {
         });
     }
 
+    pub fn name(&self) -> &SharedString {
+        &self.name
+    }
+
     pub fn width(mut self, width: gpui::Pixels) -> Self {
         self.width = Some(width);
         self
@@ -1,186 +1,378 @@
-use core::time;
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use fake::Fake;
 use gpui::{
-    actions, div, px, ElementId, FocusHandle, FocusableView, InteractiveElement, IntoElement,
-    ParentElement, Render, RenderOnce, Styled, Timer, View, ViewContext, VisualContext,
-    WindowContext,
+    actions, canvas, div, prelude::FluentBuilder as _, px, svg, AnyView, AppContext, FocusHandle,
+    FocusableView, InteractiveElement, IntoElement, ParentElement, Render, SharedString,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Task, Timer, View, ViewContext,
+    VisualContext, WindowContext,
 };
 
 use ti::{
+    button::Button,
     h_flex,
     label::Label,
     list::ListItem,
     list::{List, ListDelegate},
-    theme::{hsl, ActiveTheme, Colorize as _},
-    v_flex,
+    theme::{hsl, ActiveTheme},
+    v_flex, Selectable as _, Sizable as _,
 };
 
 actions!(list_story, [SelectedCompany]);
 
+/// How often a list sorted by price or change is sorted again while live sorting is on.
+const LIVE_SORT_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Clone)]
-struct Company {
-    name: String,
-    industry: String,
-    last_done: f64,
-    prev_close: f64,
+pub(crate) struct Company {
+    pub(crate) name: String,
+    pub(crate) industry: String,
+    pub(crate) last_done: f64,
+    pub(crate) prev_close: f64,
     // description: String,
 }
 
 impl Company {
-    fn random_update(&mut self) {
+    pub(crate) fn random_update(&mut self) {
         self.last_done = self.prev_close * (1.0 + (-0.2..0.2).fake::<f64>());
     }
 
-    fn change_percent(&self) -> f64 {
+    pub(crate) fn change_percent(&self) -> f64 {
         (self.last_done - self.prev_close) / self.prev_close
     }
 }
 
-#[derive(IntoElement)]
-struct CompanyListItem {
-    base: ListItem,
-    ix: usize,
+/// A row of the company list.
+///
+/// Each company is its own view rendered with view caching, so a price update only
+/// re-renders the row of that company instead of the whole list.
+struct CompanyRow {
     company: Company,
-    selected: bool,
-}
-
-impl CompanyListItem {
-    pub fn new(id: impl Into<ElementId>, company: Company, ix: usize, selected: bool) -> Self {
-        CompanyListItem {
-            company,
-            ix,
-            base: ListItem::new(id),
-            selected,
-        }
-    }
 }
 
-impl RenderOnce for CompanyListItem {
-    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        let text_color = if self.selected {
-            cx.theme().accent_foreground
-        } else {
-            cx.theme().foreground
-        };
-
+impl Render for CompanyRow {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let trend_color = match self.company.change_percent() {
             change if change > 0.0 => hsl(0.0, 79.0, 53.0),
             change if change < 0.0 => hsl(100.0, 79.0, 53.0),
             _ => cx.theme().foreground,
         };
 
-        let bg_color = if self.selected {
-            cx.theme().list_active
-        } else if self.ix % 2 == 0 {
-            cx.theme().list
-        } else {
-            cx.theme().list_even
-        };
-
-        self.base
-            .px_3()
-            .py_1()
-            .overflow_x_hidden()
-            .bg(bg_color)
+        h_flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .child(
+                v_flex()
+                    .gap_1()
+                    .max_w(px(500.))
+                    .overflow_x_hidden()
+                    .flex_nowrap()
+                    .child(Label::new(self.company.name.clone()).whitespace_nowrap())
+                    .child(
+                        div().text_sm().overflow_x_hidden().child(
+                            Label::new(self.company.industry.clone())
+                                .whitespace_nowrap()
+                                .text_color(cx.theme().muted_foreground),
+                        ),
+                    ),
+            )
             .child(
                 h_flex()
-                    .items_center()
-                    .justify_between()
                     .gap_2()
-                    .text_color(text_color)
+                    .items_center()
+                    .justify_end()
                     .child(
-                        v_flex()
-                            .gap_1()
-                            .max_w(px(500.))
-                            .overflow_x_hidden()
-                            .flex_nowrap()
-                            .child(Label::new(self.company.name.clone()).whitespace_nowrap())
-                            .child(
-                                div().text_sm().overflow_x_hidden().child(
-                                    Label::new(self.company.industry.clone())
-                                        .whitespace_nowrap()
-                                        .text_color(text_color.opacity(0.5)),
-                                ),
-                            ),
+                        div()
+                            .w(px(65.))
+                            .child(format!("{:.2}", self.company.last_done)),
                     )
                     .child(
-                        h_flex()
-                            .gap_2()
-                            .items_center()
-                            .justify_end()
-                            .child(
-                                div()
-                                    .w(px(65.))
-                                    .text_color(text_color)
-                                    .child(format!("{:.2}", self.company.last_done)),
-                            )
-                            .child(
-                                h_flex().w(px(65.)).justify_end().child(
-                                    div()
-                                        .rounded_md()
-                                        .whitespace_nowrap()
-                                        .text_size(px(12.))
-                                        .px_1()
-                                        .text_color(trend_color)
-                                        .child(format!("{:.2}%", self.company.change_percent())),
-                                ),
-                            ),
+                        h_flex().w(px(65.)).justify_end().child(
+                            div()
+                                .rounded_md()
+                                .whitespace_nowrap()
+                                .text_size(px(12.))
+                                .px_1()
+                                .text_color(trend_color)
+                                .child(format!("{:.2}%", self.company.change_percent())),
+                        ),
                     ),
             )
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortColumn {
+    Name,
+    Price,
+    Change,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SortOrder {
+    Ascending,
+    Descending,
+}
+
 struct CompanyListDelegate {
-    companies: Vec<Company>,
-    selected_index: usize,
+    companies: Vec<View<CompanyRow>>,
+    query: String,
+    sort: Option<(SortColumn, SortOrder)>,
+    /// The indices in `companies` of the listed companies, filtered and sorted.
+    matches: Vec<usize>,
+    /// The index in `companies` of the selected company, kept when the list is sorted.
+    selected: Option<usize>,
 }
 
 impl ListDelegate for CompanyListDelegate {
-    type Item = CompanyListItem;
+    type Item = ListItem;
 
     fn items_count(&self) -> usize {
-        self.companies.len()
+        self.matches.len()
+    }
+
+    fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
+        self.query = query.trim().to_lowercase();
+        self.update_matches(cx);
+        cx.notify();
+        Task::ready(())
     }
 
     fn confirmed_index(&self) -> Option<usize> {
-        Some(self.selected_index)
+        self.matches
+            .iter()
+            .position(|ix| Some(*ix) == self.selected)
     }
 
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
-        if let Some(ix) = ix {
-            self.selected_index = ix;
+        if let Some(ix) = ix.and_then(|ix| self.matches.get(ix)) {
+            self.selected = Some(*ix);
         }
         cx.dispatch_action(Box::new(SelectedCompany));
     }
 
     fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
-        if let Some(ix) = ix {
-            self.selected_index = ix;
+        if let Some(ix) = ix.and_then(|ix| self.matches.get(ix)) {
+            self.selected = Some(*ix);
             cx.notify();
         }
     }
 
-    fn render_item(&self, ix: usize, _cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
-        let selected = ix == self.selected_index;
-        if let Some(company) = self.companies.get(ix) {
-            return Some(CompanyListItem::new(ix, company.clone(), ix, selected));
-        }
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        let company_ix = *self.matches.get(ix)?;
+        let selected = Some(company_ix) == self.selected;
+        let row = self.companies.get(company_ix)?;
+
+        let text_color = if selected {
+            cx.theme().accent_foreground
+        } else {
+            cx.theme().foreground
+        };
+        let bg_color = if selected {
+            cx.theme().list_active
+        } else if ix % 2 == 0 {
+            cx.theme().list
+        } else {
+            cx.theme().list_even
+        };
 
-        None
+        // The selection and stripes are drawn around the cached row, so moving the
+        // selection does not re-render the rows.
+        Some(
+            ListItem::new(ix)
+                .px_3()
+                .py_1()
+                .overflow_x_hidden()
+                .bg(bg_color)
+                .text_color(text_color)
+                .child(AnyView::from(row.clone()).cached(StyleRefinement::default().w_full())),
+        )
     }
 }
 
 impl CompanyListDelegate {
-    fn selected_company(&self) -> Option<Company> {
-        self.companies.get(self.selected_index).cloned()
+    fn new(companies: Vec<View<CompanyRow>>, cx: &AppContext) -> Self {
+        let mut this = Self {
+            companies,
+            query: String::new(),
+            sort: None,
+            matches: Vec::new(),
+            selected: Some(0),
+        };
+        this.update_matches(cx);
+        this
+    }
+
+    /// Replace the companies, keeping the query and the sort.
+    fn set_companies(&mut self, companies: Vec<View<CompanyRow>>, cx: &AppContext) {
+        self.companies = companies;
+        self.selected = Some(0);
+        self.update_matches(cx);
+    }
+
+    fn selected_row(&self) -> Option<View<CompanyRow>> {
+        self.companies.get(self.selected?).cloned()
+    }
+
+    /// Filter the companies by name and industry, then sort them.
+    fn update_matches(&mut self, cx: &AppContext) {
+        let query = &self.query;
+        self.matches = self
+            .companies
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| {
+                let company = &row.read(cx).company;
+                query.is_empty()
+                    || company.name.to_lowercase().contains(query)
+                    || company.industry.to_lowercase().contains(query)
+            })
+            .map(|(ix, _)| ix)
+            .collect();
+        self.sort_matches(cx);
+    }
+
+    fn sort_matches(&mut self, cx: &AppContext) {
+        let Some((column, order)) = self.sort else {
+            return;
+        };
+
+        let companies = &self.companies;
+        self.matches.sort_by(|a, b| {
+            let (a, b) = (
+                &companies[*a].read(cx).company,
+                &companies[*b].read(cx).company,
+            );
+            let ordering = match column {
+                SortColumn::Name => a.name.cmp(&b.name),
+                SortColumn::Price => a.last_done.total_cmp(&b.last_done),
+                SortColumn::Change => a.change_percent().total_cmp(&b.change_percent()),
+            };
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    /// Whether the order depends on the prices, so it must be sorted again when they move.
+    fn sorted_by_price(&self) -> bool {
+        matches!(self.sort, Some((SortColumn::Price | SortColumn::Change, _)))
+    }
+
+    /// Sort by the column, toggling the order when it is already sorted by it.
+    fn toggle_sort(&mut self, column: SortColumn, cx: &AppContext) {
+        self.sort = match self.sort {
+            Some((sorted, SortOrder::Ascending)) if sorted == column => {
+                Some((column, SortOrder::Descending))
+            }
+            _ => Some((column, SortOrder::Ascending)),
+        };
+        self.sort_matches(cx);
+    }
+}
+
+/// How the prices of the list are updated.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum UpdateMode {
+    /// 1,000 companies, a few prices move every 500ms.
+    Live,
+    /// The given number of companies, 1% of the prices move every frame.
+    Benchmark(usize),
+}
+
+impl UpdateMode {
+    fn rows(&self) -> usize {
+        match self {
+            Self::Live => 1_000,
+            Self::Benchmark(rows) => *rows,
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        match self {
+            Self::Live => Duration::from_millis(500),
+            Self::Benchmark(_) => Duration::from_millis(16),
+        }
+    }
+
+    fn updates_per_tick(&self) -> usize {
+        match self {
+            Self::Live => self.rows() / 20,
+            Self::Benchmark(rows) => rows / 100,
+        }
+    }
+}
+
+/// The frame times and price updates of the last second, shown in benchmark mode.
+#[derive(Default)]
+struct BenchmarkStats {
+    frame_times: VecDeque<(Instant, Duration)>,
+    updates: VecDeque<(Instant, usize)>,
+}
+
+impl BenchmarkStats {
+    const WINDOW: Duration = Duration::from_secs(1);
+
+    fn record_frame(&mut self, frame_time: Duration) {
+        let now = Instant::now();
+        self.frame_times.push_back((now, frame_time));
+        while let Some((at, _)) = self.frame_times.front() {
+            if now.duration_since(*at) <= Self::WINDOW {
+                break;
+            }
+            self.frame_times.pop_front();
+        }
+    }
+
+    fn record_updates(&mut self, count: usize) {
+        let now = Instant::now();
+        self.updates.push_back((now, count));
+        while let Some((at, _)) = self.updates.front() {
+            if now.duration_since(*at) <= Self::WINDOW {
+                break;
+            }
+            self.updates.pop_front();
+        }
+    }
+
+    /// The average time to render, lay out and paint a frame.
+    fn frame_time(&self) -> Duration {
+        if self.frame_times.is_empty() {
+            return Duration::ZERO;
+        }
+        let total = self
+            .frame_times
+            .iter()
+            .map(|(_, time)| *time)
+            .sum::<Duration>();
+        total / self.frame_times.len() as u32
+    }
+
+    /// The number of prices updated in the last second.
+    fn update_rate(&self) -> usize {
+        self.updates.iter().map(|(_, count)| count).sum()
     }
 }
 
 pub struct ListStory {
     focus_handle: FocusHandle,
     company_list: View<List<CompanyListDelegate>>,
-    selected_company: Option<Company>,
+    /// The row of the selected company, the details read its prices from it.
+    selected_row: Option<View<CompanyRow>>,
+    mode: UpdateMode,
+    /// Sort the list again as prices move, otherwise its order is frozen until a sort button is
+    /// clicked.
+    live_sort: bool,
+    last_sort: Instant,
+    stats: Rc<RefCell<BenchmarkStats>>,
+    _update_task: Task<()>,
 }
 
 impl ListStory {
@@ -189,58 +381,235 @@ impl ListStory {
     }
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
-        let companies = (0..1_000)
-            .map(|_| random_company())
-            .collect::<Vec<Company>>();
-
-        let company_list = cx.new_view(|cx| {
-            List::new(
-                CompanyListDelegate {
-                    companies,
-                    selected_index: 0,
-                },
-                cx,
-            )
-            .no_query()
+        let mode = UpdateMode::Live;
+        let companies = Self::random_rows(mode.rows(), cx);
+        let company_list = cx.new_view(|cx| List::new(CompanyListDelegate::new(companies, cx), cx));
+
+        let selected_row = company_list.read(cx).delegate().selected_row();
+        Self {
+            focus_handle: cx.focus_handle(),
+            company_list,
+            selected_row,
+            mode,
+            live_sort: false,
+            last_sort: Instant::now(),
+            stats: Rc::new(RefCell::new(BenchmarkStats::default())),
+            _update_task: Self::spawn_updates(mode, cx),
+        }
+    }
+
+    fn random_rows(count: usize, cx: &mut ViewContext<Self>) -> Vec<View<CompanyRow>> {
+        (0..count)
+            .map(|_| {
+                cx.new_view(|_| CompanyRow {
+                    company: random_company(),
+                })
+            })
+            .collect()
+    }
+
+    fn set_mode(&mut self, mode: UpdateMode, cx: &mut ViewContext<Self>) {
+        if self.mode == mode {
+            return;
+        }
+
+        self.mode = mode;
+        let companies = Self::random_rows(mode.rows(), cx);
+        self.company_list.update(cx, |list, cx| {
+            list.delegate_mut().set_companies(companies, cx);
+            cx.notify();
         });
+        self.selected_row = self.company_list.read(cx).delegate().selected_row();
+        *self.stats.borrow_mut() = BenchmarkStats::default();
+        self._update_task = Self::spawn_updates(mode, cx);
+        cx.notify();
+    }
 
-        // Spawn a background to random refresh the list
+    fn spawn_updates(mode: UpdateMode, cx: &mut ViewContext<Self>) -> Task<()> {
         cx.spawn(move |this, mut cx| async move {
             loop {
-                Timer::after(time::Duration::from_secs_f64(0.5)).await;
-                this.update(&mut cx, |this, cx| {
-                    this.company_list.update(cx, |picker, _| {
-                        picker
-                            .delegate_mut()
-                            .companies
-                            .iter_mut()
-                            .for_each(|company| {
-                                company.random_update();
-                            });
-                    });
-                    cx.notify();
-                })
-                .ok();
+                Timer::after(mode.interval()).await;
+                if this
+                    .update(&mut cx, |this, cx| this.update_prices(mode, cx))
+                    .is_err()
+                {
+                    break;
+                }
             }
         })
-        .detach();
+    }
 
-        Self {
-            focus_handle: cx.focus_handle(),
-            company_list,
-            selected_company: None,
+    /// Move the prices of random companies, only their rows are notified, and the story when the
+    /// selected company moved.
+    fn update_prices(&mut self, mode: UpdateMode, cx: &mut ViewContext<Self>) {
+        let delegate = self.company_list.read(cx).delegate();
+        let count = delegate.companies.len();
+        if count == 0 {
+            return;
+        }
+        let rows = (0..mode.updates_per_tick())
+            .map(|_| delegate.companies[(0..count).fake::<usize>()].clone())
+            .collect::<Vec<_>>();
+
+        for row in &rows {
+            row.update(cx, |row, cx| {
+                row.company.random_update();
+                cx.notify();
+            });
+        }
+
+        // Prices moved, sorting all matches on every tick would cost more than the updates.
+        if self.live_sort
+            && self.last_sort.elapsed() >= LIVE_SORT_INTERVAL
+            && self.company_list.read(cx).delegate().sorted_by_price()
+        {
+            self.last_sort = Instant::now();
+            self.company_list.update(cx, |list, cx| {
+                list.delegate_mut().sort_matches(cx);
+                cx.notify();
+            });
+        }
+
+        self.stats.borrow_mut().record_updates(rows.len());
+        if self
+            .selected_row
+            .as_ref()
+            .is_some_and(|selected| rows.contains(selected))
+        {
+            cx.notify();
         }
     }
 
     fn selected_company(&mut self, _: &SelectedCompany, cx: &mut ViewContext<Self>) {
         let picker = self.company_list.read(cx);
-        if let Some(company) = picker.delegate().selected_company() {
-            self.selected_company = Some(company);
+        if let Some(row) = picker.delegate().selected_row() {
+            self.selected_row = Some(row);
+            cx.notify();
         }
     }
+
+    fn render_sort_button(
+        &self,
+        column: SortColumn,
+        label: &'static str,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let sort = self.company_list.read(cx).delegate().sort;
+        let icon = match sort {
+            Some((sorted, SortOrder::Ascending)) if sorted == column => {
+                Some("icons/sort-ascending.svg")
+            }
+            Some((sorted, SortOrder::Descending)) if sorted == column => {
+                Some("icons/sort-descending.svg")
+            }
+            _ => None,
+        };
+
+        h_flex()
+            .id(label)
+            .gap_1()
+            .px_2()
+            .py_1()
+            .items_center()
+            .rounded_md()
+            .text_sm()
+            .cursor_pointer()
+            .hover(|this| this.bg(cx.theme().list_hover))
+            .child(label)
+            .when_some(icon, |this, icon| {
+                this.child(svg().path(icon).size_4().text_color(cx.theme().foreground))
+            })
+            .on_click(cx.listener(move |this, _, cx| {
+                this.company_list.update(cx, |list, cx| {
+                    list.delegate_mut().toggle_sort(column, cx);
+                    cx.notify();
+                });
+                cx.notify();
+            }))
+    }
+
+    fn render_mode_button(
+        &self,
+        mode: UpdateMode,
+        label: &'static str,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        Button::new(label, cx)
+            .label(label)
+            .small()
+            .selected(self.mode == mode)
+            .on_click(cx.listener(move |this, _, cx| this.set_mode(mode, cx)))
+    }
+
+    fn render_live_sort_button(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        Button::new("live-sort", cx)
+            .label("Live sort")
+            .small()
+            .selected(self.live_sort)
+            .on_click(cx.listener(|this, _, cx| {
+                this.live_sort = !this.live_sort;
+                cx.notify();
+            }))
+    }
+
+    fn render_stats(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let stats = self.stats.borrow();
+        let sorting = if self.live_sort {
+            format!("sorted every {}s", LIVE_SORT_INTERVAL.as_secs())
+        } else {
+            "order frozen".to_string()
+        };
+        div()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(format!(
+                "{} rows, frame {:.1}ms, {} updates/s, {}",
+                self.mode.rows(),
+                stats.frame_time().as_secs_f64() * 1000.,
+                stats.update_rate(),
+                sorting
+            ))
+    }
+
+    fn render_detail(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let field = |label: &'static str, value: SharedString| {
+            h_flex()
+                .justify_between()
+                .child(Label::new(label).text_color(cx.theme().muted_foreground))
+                .child(value)
+        };
+
+        v_flex()
+            .w(px(300.))
+            .flex_none()
+            .gap_2()
+            .p_4()
+            .border_l_1()
+            .border_color(cx.theme().border)
+            .map(|this| match &self.selected_row {
+                Some(row) => {
+                    let company = &row.read(cx).company;
+                    this.child(Label::new(company.name.clone()).text_xl())
+                        .child(field("Industry", company.industry.clone().into()))
+                        .child(field("Last", format!("{:.2}", company.last_done).into()))
+                        .child(field(
+                            "Previous Close",
+                            format!("{:.2}", company.prev_close).into(),
+                        ))
+                        .child(field(
+                            "Change",
+                            format!("{:.2}%", company.change_percent()).into(),
+                        ))
+                }
+                None => this.child(
+                    Label::new("Select a company to see its details.")
+                        .text_color(cx.theme().muted_foreground),
+                ),
+            })
+    }
 }
 
-fn random_company() -> Company {
+pub(crate) fn random_company() -> Company {
     let last_done = (0.0..999.0).fake::<f64>();
     let prev_close = last_done * (-0.1..0.1).fake::<f64>();
     Company {
@@ -259,14 +628,72 @@ impl FocusableView for ListStory {
 
 impl Render for ListStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // Measured from here to the paint of the last child, so it covers the render, layout
+        // and paint of the rows. Shown on the next frame.
+        let frame_start = Instant::now();
+        let stats = self.stats.clone();
+
         div()
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::selected_company))
             .size_full()
-            .gap_4()
+            .flex()
             .border_1()
             .border_color(cx.theme().border)
             .rounded_md()
-            .child(self.company_list.clone())
+            .child(
+                v_flex()
+                    .flex_1()
+                    .h_full()
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .p_1()
+                            .justify_between()
+                            .border_b_1()
+                            .border_color(cx.theme().border)
+                            .child(
+                                h_flex()
+                                    .gap_1()
+                                    .items_center()
+                                    .child(self.render_mode_button(UpdateMode::Live, "Live", cx))
+                                    .child(self.render_mode_button(
+                                        UpdateMode::Benchmark(10_000),
+                                        "10k rows",
+                                        cx,
+                                    ))
+                                    .child(self.render_mode_button(
+                                        UpdateMode::Benchmark(100_000),
+                                        "100k rows",
+                                        cx,
+                                    ))
+                                    .when(self.mode != UpdateMode::Live, |this| {
+                                        this.child(self.render_stats(cx))
+                                    }),
+                            )
+                            .child(
+                                h_flex()
+                                    .gap_1()
+                                    .child(self.render_live_sort_button(cx))
+                                    .child(self.render_sort_button(SortColumn::Name, "Name", cx))
+                                    .child(self.render_sort_button(SortColumn::Price, "Price", cx))
+                                    .child(self.render_sort_button(
+                                        SortColumn::Change,
+                                        "Change %",
+                                        cx,
+                                    )),
+                            ),
+                    )
+                    .child(self.company_list.clone()),
+            )
+            .child(self.render_detail(cx))
+            .child(
+                canvas(
+                    |_, _| {},
+                    move |_, _, _| stats.borrow_mut().record_frame(frame_start.elapsed()),
+                )
+                .absolute()
+                .size_full(),
+            )
     }
 }
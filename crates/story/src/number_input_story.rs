@@ -0,0 +1,118 @@
+use components::number_input::{format_number, NumberInput, NumberInputEvent};
+use gpui::{
+    IntoElement, ParentElement as _, Render, Styled, View, ViewContext, VisualContext,
+    WindowContext,
+};
+use ti::{h_flex, v_flex};
+
+use crate::section;
+
+pub struct NumberInputStory {
+    quantity: View<NumberInput>,
+    ratio: View<NumberInput>,
+    price: View<NumberInput>,
+    disabled: View<NumberInput>,
+    quantity_value: Option<f64>,
+    ratio_value: Option<f64>,
+    price_value: Option<f64>,
+}
+
+impl NumberInputStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let quantity = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).integer().min(0.).max(99.);
+            input.set_value(1., cx);
+            input
+        });
+        let ratio = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).decimal(2).min(0.).max(1.).step(0.05);
+            input.set_value(0.5, cx);
+            input
+        });
+        let price = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).decimal(2).min(0.).step(100.);
+            input.set_value(1234567.89, cx);
+            input
+        });
+        let disabled = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx);
+            input.set_value(42., cx);
+            input.set_disabled(true, cx);
+            input
+        });
+
+        cx.subscribe(&quantity, |this, _, event: &NumberInputEvent, cx| {
+            let NumberInputEvent::Change(value) = event;
+            this.quantity_value = *value;
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&ratio, |this, _, event: &NumberInputEvent, cx| {
+            let NumberInputEvent::Change(value) = event;
+            this.ratio_value = *value;
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&price, |this, _, event: &NumberInputEvent, cx| {
+            let NumberInputEvent::Change(value) = event;
+            this.price_value = *value;
+            cx.notify();
+        })
+        .detach();
+
+        Self {
+            quantity,
+            ratio,
+            price,
+            disabled,
+            quantity_value: Some(1.),
+            ratio_value: Some(0.5),
+            price_value: Some(1234567.89),
+        }
+    }
+}
+
+impl Render for NumberInputStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(
+                        section("Integer, from 0 to 99", cx)
+                            .child(self.quantity.clone())
+                            .child(format!("Value: {:?}", self.quantity_value)),
+                    )
+                    .child(
+                        section("Decimal, from 0 to 1 by 0.05", cx)
+                            .child(self.ratio.clone())
+                            .child(format!("Value: {:?}", self.ratio_value)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(
+                        section("Formatted for the locale, by 100", cx)
+                            .child(self.price.clone())
+                            .child(match self.price_value {
+                                Some(value) => {
+                                    format!("Value: {} ({})", value, format_number(value, 2, true))
+                                }
+                                None => "Value: None".to_string(),
+                            }),
+                    )
+                    .child(section("Disabled", cx).child(self.disabled.clone())),
+            )
+            .child("Use the buttons, the up and down keys or the scroll wheel to step the value.")
+    }
+}
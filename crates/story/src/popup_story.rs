@@ -1,3 +1,7 @@
+use components::{
+    clipboard,
+    form::{self, FieldError, FormField},
+};
 use gpui::{
     actions, div, px, AnchorCorner, AppContext, DismissEvent, Element, EventEmitter, FocusHandle,
     FocusableView, InteractiveElement, IntoElement, KeyBinding, MouseButton, MouseDownEvent,
@@ -30,25 +34,88 @@ pub fn init(cx: &mut AppContext) {
     ])
 }
 
+/// The value submitted by [`Form`].
+struct Contact {
+    name: String,
+    email: String,
+    age: Option<u8>,
+}
+
 struct Form {
-    input1: View<TextInput>,
+    form: View<form::Form<Contact>>,
 }
 
 impl Form {
     fn new(cx: &mut WindowContext) -> View<Self> {
         cx.new_view(|cx| Self {
-            input1: cx.new_view(TextInput::new),
+            form: cx.new_view(|cx| {
+                form::Form::new(
+                    |values| {
+                        let age = match values.optional("age") {
+                            Some(_) => Some(values.parse("age")?),
+                            None => None,
+                        };
+                        if age == Some(0) {
+                            return Err(FieldError {
+                                field: "age".into(),
+                                message: "Must be greater than 0.".into(),
+                            });
+                        }
+
+                        Ok(Contact {
+                            name: values.get("name").trim().to_string(),
+                            email: values.get("email").trim().to_string(),
+                            age,
+                        })
+                    },
+                    cx,
+                )
+                .field(
+                    FormField::new(
+                        "name",
+                        "Name",
+                        cx.new_view(|cx| TextInput::new(cx).placeholder("Your name")),
+                    )
+                    .required()
+                    .rule(form::min_length(2)),
+                    cx,
+                )
+                .field(
+                    FormField::new(
+                        "email",
+                        "Email",
+                        cx.new_view(|cx| TextInput::new(cx).placeholder("you@example.com")),
+                    )
+                    .required()
+                    .rule(form::email()),
+                    cx,
+                )
+                .field(
+                    FormField::new("age", "Age", cx.new_view(TextInput::new))
+                        .description("A number from 1 to 255."),
+                    cx,
+                )
+            }),
         })
     }
+
+    fn submit(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(contact) = self.form.update(cx, |form, cx| form.submit(cx)) {
+            self.form.update(cx, |form, cx| form.reset(cx));
+            cx.emit(contact);
+            cx.emit(DismissEvent);
+        }
+    }
 }
 
 impl FocusableView for Form {
     fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
-        self.input1.focus_handle(cx)
+        self.form.focus_handle(cx)
     }
 }
 
 impl EventEmitter<DismissEvent> for Form {}
+impl EventEmitter<Contact> for Form {}
 
 impl Render for Form {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
@@ -57,12 +124,12 @@ impl Render for Form {
             .p_4()
             .size_full()
             .child("This is a form container.")
-            .child(self.input1.clone())
+            .child(self.form.clone())
             .child(
                 Button::new("submit", cx)
                     .label("Submit")
                     .primary()
-                    .on_click(cx.listener(|_, _, cx| cx.emit(DismissEvent))),
+                    .on_click(cx.listener(|this, _, cx| this.submit(cx))),
             )
     }
 }
@@ -81,6 +148,17 @@ impl PopupStory {
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
         let form = Form::new(cx);
+        cx.subscribe(&form, |this, _, contact: &Contact, cx| {
+            this.message = match contact.age {
+                Some(age) => format!(
+                    "Submitted {} <{}>, {} years old",
+                    contact.name, contact.email, age
+                ),
+                None => format!("Submitted {} <{}>", contact.name, contact.email),
+            };
+            cx.notify();
+        })
+        .detach();
         Self {
             form,
             focus_handle: cx.focus_handle(),
@@ -89,16 +167,31 @@ impl PopupStory {
         }
     }
 
+    /// The text copied or cut from the story, the message or a greeting without one.
+    fn copied_text(&self) -> String {
+        if self.message.is_empty() {
+            "Hello from the popup story".to_string()
+        } else {
+            self.message.clone()
+        }
+    }
+
     fn on_copy(&mut self, _: &Copy, cx: &mut ViewContext<Self>) {
-        self.message = "You have clicked copy".to_string();
+        let text = self.copied_text();
+        self.message = format!("Copied \"{}\"", text);
+        clipboard::copy(text, cx);
         cx.notify()
     }
     fn on_cut(&mut self, _: &Cut, cx: &mut ViewContext<Self>) {
-        self.message = "You have clicked cut".to_string();
+        clipboard::copy(self.copied_text(), cx);
+        self.message.clear();
         cx.notify()
     }
     fn on_paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
-        self.message = "You have clicked paste".to_string();
+        self.message = match clipboard::paste(cx) {
+            Some(content) => format!("Pasted {}: {}", content.kind(), content.text()),
+            None => "The clipboard is empty".to_string(),
+        };
         cx.notify()
     }
     fn on_search_all(&mut self, _: &SearchAll, cx: &mut ViewContext<Self>) {
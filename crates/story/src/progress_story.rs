@@ -0,0 +1,188 @@
+use std::time::Duration;
+
+use components::{progress::Progress, skeleton, spinner::Spinner};
+use gpui::{
+    div, px, IntoElement, ParentElement as _, Render, SharedString, Styled, Task, Timer, View,
+    ViewContext, VisualContext, WindowContext,
+};
+use ti::{
+    button::Button, h_flex, label::Label, list::ListItem, theme::ActiveTheme, v_flex, Sizable as _,
+};
+
+use crate::section;
+
+/// How long the simulated load waits before its size is known.
+const CONNECT_DELAY: Duration = Duration::from_millis(1200);
+/// The number of chunks of the simulated load, and the time each one takes.
+const CHUNKS: usize = 20;
+const CHUNK_DELAY: Duration = Duration::from_millis(120);
+
+const ITEMS: [&str; 5] = [
+    "Apple Inc.",
+    "Microsoft Corporation",
+    "Alphabet Inc.",
+    "Amazon.com, Inc.",
+    "NVIDIA Corporation",
+];
+
+enum LoadState {
+    /// Waiting for the size of the load, the progress is indeterminate.
+    Connecting,
+    /// The number of chunks loaded.
+    Loading(usize),
+    Loaded(Vec<SharedString>),
+}
+
+pub struct ProgressStory {
+    state: LoadState,
+    _load_task: Task<()>,
+}
+
+impl ProgressStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self {
+            state: LoadState::Connecting,
+            _load_task: Task::ready(()),
+        };
+        this.load(cx);
+        this
+    }
+
+    /// Simulate an async load, connecting first then receiving the chunks one by one.
+    fn load(&mut self, cx: &mut ViewContext<Self>) {
+        self.state = LoadState::Connecting;
+        self._load_task = cx.spawn(|this, mut cx| async move {
+            Timer::after(CONNECT_DELAY).await;
+            for chunk in 0..=CHUNKS {
+                let updated = this.update(&mut cx, |this, cx| {
+                    this.state = LoadState::Loading(chunk);
+                    cx.notify();
+                });
+                if updated.is_err() {
+                    return;
+                }
+                Timer::after(CHUNK_DELAY).await;
+            }
+            this.update(&mut cx, |this, cx| {
+                this.state = LoadState::Loaded(ITEMS.iter().map(|item| (*item).into()).collect());
+                cx.notify();
+            })
+            .ok();
+        });
+        cx.notify();
+    }
+
+    fn render_load(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let (status, progress) = match &self.state {
+            LoadState::Connecting => ("Connecting…".to_string(), Progress::new()),
+            LoadState::Loading(chunk) => {
+                let value = *chunk as f32 / CHUNKS as f32 * 100.;
+                (
+                    format!("Loading {:.0}%", value),
+                    Progress::new().value(value),
+                )
+            }
+            LoadState::Loaded(items) => (
+                format!("Loaded {} items", items.len()),
+                Progress::new().value(100.),
+            ),
+        };
+
+        let content = match &self.state {
+            LoadState::Loaded(items) => v_flex()
+                .w_full()
+                .gap_3()
+                .child(
+                    v_flex().w_full().children(
+                        items
+                            .iter()
+                            .enumerate()
+                            .map(|(ix, item)| ListItem::new(ix).px_3().py_1().child(item.clone())),
+                    ),
+                )
+                .child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .p_4()
+                        .rounded_lg()
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .child(Label::new("Summary"))
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child("All the companies were loaded."),
+                        ),
+                ),
+            _ => v_flex()
+                .w_full()
+                .gap_3()
+                .child(
+                    v_flex()
+                        .w_full()
+                        .children((0..ITEMS.len()).map(skeleton::list_item)),
+                )
+                .child(skeleton::section(1, cx)),
+        };
+
+        v_flex()
+            .w_full()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(
+                        Button::new("reload", cx)
+                            .label("Reload")
+                            .small()
+                            .on_click(cx.listener(|this, _, cx| this.load(cx))),
+                    )
+                    .child(div().text_sm().child(status)),
+            )
+            .child(progress)
+            .child(content)
+    }
+}
+
+impl Render for ProgressStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .child(
+                section("Progress", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(Progress::new().value(25.))
+                        .child(Progress::new().value(70.))
+                        .child(Progress::new()),
+                ),
+            )
+            .child(
+                section("Spinner", cx).child(
+                    h_flex()
+                        .gap_4()
+                        .items_center()
+                        .child(Spinner::new())
+                        .child(Spinner::new().circle())
+                        .child(Spinner::new().size(px(24.)).color(cx.theme().primary))
+                        .child(
+                            Spinner::new()
+                                .circle()
+                                .size(px(32.))
+                                .color(cx.theme().primary),
+                        ),
+                ),
+            )
+            .child(section("Async load", cx).child(self.render_load(cx)))
+    }
+}
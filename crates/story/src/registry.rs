@@ -0,0 +1,209 @@
+use gpui::{AnyView, AppContext, Global, SharedString, View, VisualContext as _, WindowContext};
+use workspace::{pane::Pane, Workspace};
+
+use crate::StoryContainer;
+
+/// A story that can be opened in the workspace.
+#[derive(Clone)]
+pub struct StoryEntry {
+    pub name: SharedString,
+    pub description: SharedString,
+    pub category: SharedString,
+    pub build: fn(&mut WindowContext) -> AnyView,
+}
+
+impl StoryEntry {
+    pub fn new(
+        name: impl Into<SharedString>,
+        description: impl Into<SharedString>,
+        category: impl Into<SharedString>,
+        build: fn(&mut WindowContext) -> AnyView,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            category: category.into(),
+            build,
+        }
+    }
+
+    /// Open the story as a new tab of the given pane.
+    pub fn open(&self, pane: &View<Pane>, cx: &mut WindowContext) -> View<StoryContainer> {
+        let story = (self.build)(cx);
+        let view = cx.new_view(|cx| {
+            StoryContainer::new(self.name.clone(), self.description.clone(), cx).story(story)
+        });
+        pane.update(cx, |pane, cx| {
+            pane.add_item(Box::new(view.clone()), true, true, None, cx);
+        });
+        view
+    }
+}
+
+/// A registration hook of a crate with stories, it runs before the app starts.
+pub type RegisterStories = fn(&mut StoryRegistry);
+
+/// The stories that can be opened, in registration order.
+///
+/// The app fills the registry with the [`RegisterStories`] hooks of the crates with stories,
+/// like [`crate::register_stories`], before it starts, so the command line sees every story.
+/// Stories added later with [`StoryRegistry::register`] can only be opened from the app.
+#[derive(Default)]
+pub struct StoryRegistry {
+    stories: Vec<StoryEntry>,
+}
+
+impl Global for StoryRegistry {}
+
+impl StoryRegistry {
+    /// A registry filled by the given hooks, they don't need a running app.
+    pub fn new(hooks: &[RegisterStories]) -> Self {
+        let mut registry = Self::default();
+        for register in hooks {
+            register(&mut registry);
+        }
+        registry
+    }
+
+    /// Make this the registry of the app.
+    pub fn set_global(self, cx: &mut AppContext) {
+        cx.set_global(self);
+    }
+
+    /// Add a story, replacing any story with the same name, ignoring case.
+    pub fn add(&mut self, story: StoryEntry) {
+        match self
+            .stories
+            .iter_mut()
+            .find(|s| same_name(&s.name, &story.name))
+        {
+            Some(existing) => *existing = story,
+            None => self.stories.push(story),
+        }
+    }
+
+    pub fn entries(&self) -> &[StoryEntry] {
+        &self.stories
+    }
+
+    /// Find a story by name, ignoring case.
+    pub fn get(&self, name: &str) -> Option<&StoryEntry> {
+        self.stories
+            .iter()
+            .find(|story| same_name(&story.name, name))
+    }
+
+    /// Add a story to the registry of the app, replacing any story with the same name.
+    pub fn register(story: StoryEntry, cx: &mut AppContext) {
+        cx.default_global::<Self>().add(story);
+    }
+
+    pub fn stories(cx: &AppContext) -> Vec<StoryEntry> {
+        cx.try_global::<Self>()
+            .map(|registry| registry.stories.clone())
+            .unwrap_or_default()
+    }
+
+    /// The categories of the given stories, in the order of their first story.
+    pub fn categories(stories: &[StoryEntry]) -> Vec<SharedString> {
+        let mut categories: Vec<SharedString> = Vec::new();
+        for story in stories {
+            if !categories.contains(&story.category) {
+                categories.push(story.category.clone());
+            }
+        }
+        categories
+    }
+
+    /// Find a story of the registry of the app by name, ignoring case.
+    pub fn find(name: &str, cx: &AppContext) -> Option<StoryEntry> {
+        cx.try_global::<Self>()?.get(name).cloned()
+    }
+}
+
+/// Story names are compared ignoring case, when adding and looking up stories alike.
+fn same_name(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+/// Focus the tab of the named story, or open it in the active pane.
+///
+/// The name is looked up ignoring case, as in [`StoryRegistry::find`].
+pub fn open_story(name: &str, workspace: &View<Workspace>, cx: &mut WindowContext) {
+    let Some(story) = StoryRegistry::find(name, cx) else {
+        log::error!("unknown story {:?}", name);
+        return;
+    };
+
+    let panes = workspace.read(cx).panes().to_vec();
+    for pane in panes {
+        let ix = pane.read(cx).items().position(|item| {
+            item.downcast::<StoryContainer>()
+                .map_or(false, |view| view.read(cx).name() == &story.name)
+        });
+        if let Some(ix) = ix {
+            pane.update(cx, |pane, cx| pane.activate_item(ix, true, true, cx));
+            return;
+        }
+    }
+
+    let pane = workspace.read(cx).active_pane().clone();
+    story.open(&pane, cx);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn story(name: &str, description: &str) -> StoryEntry {
+        StoryEntry::new(
+            name.to_string(),
+            description.to_string(),
+            "Test",
+            |_| unreachable!(),
+        )
+    }
+
+    #[test]
+    fn test_add_replaces_story_with_same_name() {
+        let mut registry = StoryRegistry::default();
+        registry.add(story("Button", "first"));
+        registry.add(story("Input", "input"));
+        registry.add(story("button", "second"));
+
+        let names = registry
+            .entries()
+            .iter()
+            .map(|story| story.name.as_ref())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["button", "Input"]);
+        assert_eq!(
+            registry
+                .get("BUTTON")
+                .map(|story| story.description.as_ref()),
+            Some("second")
+        );
+        assert!(registry.get("slider").is_none());
+    }
+
+    #[test]
+    fn test_new_runs_hooks() {
+        fn hook(registry: &mut StoryRegistry) {
+            registry.add(story("Custom", "custom"));
+        }
+
+        let registry = StoryRegistry::new(&[hook, crate::register_stories]);
+        assert!(registry.get("custom").is_some());
+        assert!(registry.get("buttons").is_some());
+        assert_eq!(
+            registry.entries().len(),
+            crate::stories().len() + 1,
+            "{:?}",
+            registry
+                .entries()
+                .iter()
+                .map(|s| &s.name)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use components::{
+    number_input::{NumberInput, NumberInputEvent},
+    slider::{Slider, SliderEvent, SliderValue},
+};
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, Styled, View, ViewContext, VisualContext,
+    WindowContext,
+};
+use ti::{h_flex, v_flex};
+
+use crate::section;
+
+fn format_value(value: SliderValue) -> String {
+    match value {
+        SliderValue::Single(value) => format!("{}", value),
+        SliderValue::Range(start, end) => format!("{} - {}", start, end),
+    }
+}
+
+pub struct SliderStory {
+    volume: View<Slider>,
+    volume_input: View<NumberInput>,
+    price: View<Slider>,
+    bass: View<Slider>,
+    treble: View<Slider>,
+    disabled: View<Slider>,
+    price_change: SliderValue,
+    price_release: SliderValue,
+}
+
+impl SliderStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let volume = cx.new_view(|cx| Slider::new(cx).step(5.).ticks(10.).default_value(50.));
+        let volume_input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).integer().min(0.).max(100.).step(5.);
+            input.set_value(50., cx);
+            input
+        });
+        let price = cx.new_view(|cx| {
+            Slider::new(cx)
+                .max(1000.)
+                .step(10.)
+                .ticks(100.)
+                .range(200., 600.)
+                .with_input(cx)
+        });
+        let bass = cx.new_view(|cx| Slider::new(cx).vertical().min(-12.).max(12.).ticks(3.));
+        let treble = cx.new_view(|cx| {
+            Slider::new(cx)
+                .vertical()
+                .min(-12.)
+                .max(12.)
+                .step(0.5)
+                .default_value(4.5)
+                .with_input(cx)
+        });
+        let disabled = cx.new_view(|cx| {
+            let mut slider = Slider::new(cx).range(20., 80.);
+            slider.set_disabled(true, cx);
+            slider
+        });
+
+        // Keep the slider and the number input in sync, in both directions.
+        cx.subscribe(&volume, |this, _, event: &SliderEvent, cx| {
+            if let SliderEvent::Change(value) = event {
+                this.volume_input
+                    .update(cx, |input, cx| input.set_value(value.start(), cx));
+            }
+        })
+        .detach();
+        cx.subscribe(&volume_input, |this, _, event: &NumberInputEvent, cx| {
+            if let NumberInputEvent::Change(Some(value)) = event {
+                this.volume.update(cx, |slider, cx| {
+                    slider.set_value(SliderValue::Single(*value), cx)
+                });
+            }
+        })
+        .detach();
+
+        cx.subscribe(&price, |this, _, event: &SliderEvent, cx| {
+            match event {
+                SliderEvent::Change(value) => this.price_change = *value,
+                SliderEvent::Release(value) => this.price_release = *value,
+            }
+            cx.notify();
+        })
+        .detach();
+
+        let price_value = price.read(cx).value();
+        Self {
+            volume,
+            volume_input,
+            price,
+            bass,
+            treble,
+            disabled,
+            price_change: price_value,
+            price_release: price_value,
+        }
+    }
+}
+
+impl Render for SliderStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .child(
+                section("Bound to a number input", cx).child(
+                    h_flex()
+                        .w_full()
+                        .gap_4()
+                        .child(self.volume.clone())
+                        .child(h_flex().w(px(160.)).child(self.volume_input.clone())),
+                ),
+            )
+            .child(
+                section("Range with inputs", cx)
+                    .child(self.price.clone())
+                    .child(format!(
+                        "Dragging: {}, released: {}",
+                        format_value(self.price_change),
+                        format_value(self.price_release)
+                    )),
+            )
+            .child(
+                section("Vertical", cx).child(
+                    h_flex()
+                        .h(px(200.))
+                        .gap_6()
+                        .child(self.bass.clone())
+                        .child(self.treble.clone()),
+                ),
+            )
+            .child(section("Disabled", cx).child(self.disabled.clone()))
+    }
+}
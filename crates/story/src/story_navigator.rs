@@ -0,0 +1,204 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement, IntoElement, ParentElement, Pixels, Render, SharedString,
+    StatefulInteractiveElement, Styled, Subscription, View, ViewContext, VisualContext, WeakView,
+    WindowContext,
+};
+use ti::{
+    input::{InputEvent, TextInput},
+    label::Label,
+    theme::ActiveTheme,
+    v_flex,
+};
+use workspace::{
+    dock::{DockPosition, Panel, PanelEvent},
+    Workspace,
+};
+
+use crate::{open_story, StoryContainer, StoryRegistry};
+
+/// A panel listing the registered stories by category, with a filter.
+pub struct StoryNavigator {
+    focus_handle: FocusHandle,
+    workspace: WeakView<Workspace>,
+    filter_input: View<TextInput>,
+    query: String,
+    /// The name of the story in the active tab.
+    active_story: Option<SharedString>,
+    position: DockPosition,
+    width: Option<Pixels>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl StoryNavigator {
+    /// Add the navigator to the left dock of the workspace.
+    pub fn add_panel(workspace: View<Workspace>, cx: &mut WindowContext) {
+        let panel = cx.new_view(|cx| Self::new(workspace.clone(), cx));
+        workspace.update(cx, |workspace, cx| workspace.add_panel(panel, cx));
+    }
+
+    fn new(workspace: View<Workspace>, cx: &mut ViewContext<Self>) -> Self {
+        let filter_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .cleanable()
+                .placeholder("Filter stories...")
+        });
+
+        let _subscriptions = vec![
+            cx.subscribe(&filter_input, |this, _, event: &InputEvent, cx| {
+                if let InputEvent::Change(text) = event {
+                    this.query = text.to_string();
+                    cx.notify();
+                }
+            }),
+            cx.subscribe(
+                &workspace,
+                |this, workspace, event: &workspace::Event, cx| match event {
+                    // Focusing another pane changes the active story too.
+                    workspace::Event::ActiveItemChanged | workspace::Event::ActivePaneChanged => {
+                        this.update_active_story(&workspace, cx)
+                    }
+                    _ => {}
+                },
+            ),
+        ];
+
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            workspace: workspace.downgrade(),
+            filter_input,
+            query: String::new(),
+            active_story: None,
+            position: DockPosition::Left,
+            width: None,
+            _subscriptions,
+        };
+        this.update_active_story(&workspace, cx);
+        this
+    }
+
+    fn update_active_story(&mut self, workspace: &View<Workspace>, cx: &mut ViewContext<Self>) {
+        self.active_story = workspace
+            .read(cx)
+            .active_pane()
+            .read(cx)
+            .active_item()
+            .and_then(|item| item.downcast::<StoryContainer>())
+            .map(|story| story.read(cx).name().clone());
+        cx.notify();
+    }
+
+    fn open(&mut self, name: &SharedString, cx: &mut ViewContext<Self>) {
+        if let Some(workspace) = self.workspace.upgrade() {
+            open_story(name, &workspace, cx);
+        }
+    }
+}
+
+impl Panel for StoryNavigator {
+    fn persistent_name() -> &'static str {
+        "story-navigator"
+    }
+
+    fn can_position(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn position(&self, _cx: &WindowContext) -> DockPosition {
+        self.position
+    }
+
+    fn set_position(&mut self, position: DockPosition, cx: &mut ViewContext<Self>) {
+        self.position = position;
+        cx.notify()
+    }
+
+    fn size(&self, _cx: &WindowContext) -> Pixels {
+        self.width.unwrap_or(px(240.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, cx: &mut ViewContext<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+}
+
+impl EventEmitter<PanelEvent> for StoryNavigator {}
+
+impl FocusableView for StoryNavigator {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for StoryNavigator {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let query = self.query.trim().to_lowercase();
+        let stories = StoryRegistry::stories(cx)
+            .into_iter()
+            .filter(|story| {
+                query.is_empty()
+                    || story.name.to_lowercase().contains(&query)
+                    || story.category.to_lowercase().contains(&query)
+            })
+            .collect::<Vec<_>>();
+
+        let categories = StoryRegistry::categories(&stories);
+
+        v_flex()
+            .id("story-navigator")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .gap_2()
+            .p_2()
+            .child(self.filter_input.clone())
+            .child(
+                v_flex()
+                    .id("story-navigator-list")
+                    .flex_1()
+                    .gap_2()
+                    .overflow_y_scroll()
+                    .children(categories.into_iter().map(|category| {
+                        v_flex()
+                            .child(
+                                Label::new(category.clone())
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground),
+                            )
+                            .children(
+                                stories
+                                    .iter()
+                                    .filter(|story| story.category == category)
+                                    .map(|story| {
+                                        let name = story.name.clone();
+                                        let is_active = self.active_story.as_ref() == Some(&name);
+
+                                        div()
+                                            .id(SharedString::from(format!("story-{}", name)))
+                                            .px_2()
+                                            .py_1()
+                                            .rounded_md()
+                                            .text_sm()
+                                            .when(is_active, |this| this.bg(cx.theme().list_active))
+                                            .when(!is_active, |this| {
+                                                this.hover(|this| this.bg(cx.theme().list_hover))
+                                            })
+                                            .child(name.clone())
+                                            .on_click(
+                                                cx.listener(move |this, _, cx| {
+                                                    this.open(&name, cx)
+                                                }),
+                                            )
+                                    }),
+                            )
+                    })),
+            )
+            .when(stories.is_empty(), |this| {
+                this.child(
+                    Label::new("No stories found.")
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+    }
+}
@@ -1,6 +1,8 @@
+use components::radio::{Radio, RadioGroup, RadioGroupEvent};
 use gpui::{
-    Div, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
-    VisualContext as _, WindowContext,
+    actions, AppContext, Div, FocusHandle, FocusableView, InteractiveElement as _, IntoElement,
+    KeyBinding, ParentElement, Render, SharedString, Styled, View, ViewContext, VisualContext as _,
+    WindowContext,
 };
 
 use ti::{
@@ -8,28 +10,101 @@ use ti::{
     label::Label,
     switch::{LabelSide, Switch},
     theme::ActiveTheme,
-    v_flex, Disableable as _, Sizable, StyledExt,
+    v_flex, Disableable as _, FocusableCycle, Sizable, StyledExt,
 };
 
-#[derive(Default)]
+actions!(switch_story, [Tab, TabPrev]);
+
+const CONTEXT: &str = "SwitchStory";
+
+const NOTIFY_OPTIONS: [&str; 3] = [
+    "All new messages",
+    "Direct messages and mentions",
+    "Nothing",
+];
+const SIZE_OPTIONS: [&str; 3] = ["Small", "Medium", "Large"];
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("shift-tab", TabPrev, Some(CONTEXT)),
+        KeyBinding::new("tab", Tab, Some(CONTEXT)),
+    ])
+}
+
 pub struct SwitchStory {
     switch1: bool,
     switch2: bool,
     switch3: bool,
+    notify: View<RadioGroup>,
+    size: View<RadioGroup>,
+    notify_value: SharedString,
+    size_value: SharedString,
 }
 
 impl SwitchStory {
     pub fn view(cx: &mut WindowContext) -> View<Self> {
-        cx.new_view(|cx| Self::new(cx))
+        cx.new_view(Self::new)
     }
 
-    pub fn new(_: &mut WindowContext) -> Self {
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let notify = cx.new_view(|cx| {
+            RadioGroup::new(cx)
+                .option(
+                    Radio::new(NOTIFY_OPTIONS[0]).description("Every message, in every channel."),
+                    cx,
+                )
+                .option(Radio::new(NOTIFY_OPTIONS[1]), cx)
+                .option(Radio::new(NOTIFY_OPTIONS[2]), cx)
+                .selected(1)
+        });
+        let size = cx.new_view(|cx| {
+            RadioGroup::new(cx)
+                .horizontal()
+                .label_side(LabelSide::Left)
+                .option(Radio::new(SIZE_OPTIONS[0]), cx)
+                .option(Radio::new(SIZE_OPTIONS[1]), cx)
+                .option(Radio::new(SIZE_OPTIONS[2]).disabled(true), cx)
+                .selected(0)
+        });
+
+        cx.subscribe(&notify, |this, _, event: &RadioGroupEvent, cx| {
+            let RadioGroupEvent::Change(ix) = event;
+            this.notify_value = NOTIFY_OPTIONS[*ix].into();
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&size, |this, _, event: &RadioGroupEvent, cx| {
+            let RadioGroupEvent::Change(ix) = event;
+            this.size_value = SIZE_OPTIONS[*ix].into();
+            cx.notify();
+        })
+        .detach();
+
         Self {
             switch1: true,
             switch2: false,
             switch3: true,
+            notify,
+            size,
+            notify_value: NOTIFY_OPTIONS[1].into(),
+            size_value: SIZE_OPTIONS[0].into(),
         }
     }
+
+    fn tab(&mut self, _: &Tab, cx: &mut ViewContext<Self>) {
+        self.cycle_focus(true, cx);
+    }
+
+    fn tab_prev(&mut self, _: &TabPrev, cx: &mut ViewContext<Self>) {
+        self.cycle_focus(false, cx);
+    }
+}
+
+impl FocusableCycle for SwitchStory {
+    // Each radio group is a single stop, its arrow keys move between its options.
+    fn cycle_focus_handles(&self, cx: &mut ViewContext<Self>) -> Vec<FocusHandle> {
+        vec![self.notify.focus_handle(cx), self.size.focus_handle(cx)]
+    }
 }
 
 impl Render for SwitchStory {
@@ -54,6 +129,9 @@ impl Render for SwitchStory {
         }
 
         v_flex().gap_6()
+        .key_context(CONTEXT)
+        .on_action(cx.listener(Self::tab))
+        .on_action(cx.listener(Self::tab_prev))
         .child(
             v_flex()
                 .items_start()
@@ -120,6 +198,16 @@ impl Render for SwitchStory {
                             cx.notify();
                         })),
                     )
+                )
+                .child(
+                    card(cx).v_flex()
+                        .items_start().child(title("Radio Groups").child(
+                            Label::new("Press tab to move between the groups, and the arrow keys to pick an option.").text_color(theme.muted_foreground)
+                        ))
+                        .child(self.notify.clone())
+                        .child(format!("Notify me about: {}", self.notify_value))
+                        .child(self.size.clone())
+                        .child(format!("Size: {}", self.size_value)),
                 ),
             )
         )
@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use components::table::{Column, SelectionMode, SortOrder, Table, TableDelegate, TableEvent};
+use fake::Fake;
+use gpui::{
+    div, px, IntoElement, ParentElement as _, Render, Styled, Subscription, Task, Timer, View,
+    ViewContext, VisualContext, WindowContext,
+};
+use ti::{
+    h_flex,
+    switch::Switch,
+    theme::{hsl, ActiveTheme},
+    v_flex,
+};
+
+use crate::list_story::{random_company, Company};
+
+const ROWS: usize = 1_000;
+/// The number of companies with the benchmark switch on.
+const BENCHMARK_ROWS: usize = 100_000;
+/// One price in `UPDATE_RATIO` moves every 500ms.
+const UPDATE_RATIO: usize = 20;
+
+struct CompanyTableDelegate {
+    companies: Vec<Company>,
+}
+
+impl TableDelegate for CompanyTableDelegate {
+    fn columns(&self) -> Vec<Column> {
+        vec![
+            Column::new("Name", px(240.)).sortable(),
+            Column::new("Industry", px(220.)).sortable(),
+            Column::new("Last", px(100.)).sortable().align_right(),
+            Column::new("Prev Close", px(100.)).align_right(),
+            Column::new("Change %", px(100.)).sortable().align_right(),
+        ]
+    }
+
+    fn rows_count(&self) -> usize {
+        self.companies.len()
+    }
+
+    fn render_cell(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let Some(company) = self.companies.get(row_ix) else {
+            return div();
+        };
+
+        match col_ix {
+            0 => div().child(company.name.clone()),
+            1 => div().child(company.industry.clone()),
+            2 => div().child(format!("{:.2}", company.last_done)),
+            3 => div().child(format!("{:.2}", company.prev_close)),
+            _ => {
+                let change = company.change_percent();
+                let color = match change {
+                    change if change > 0.0 => hsl(0.0, 79.0, 53.0),
+                    change if change < 0.0 => hsl(100.0, 79.0, 53.0),
+                    _ => cx.theme().foreground,
+                };
+                div().text_color(color).child(format!("{:.2}%", change))
+            }
+        }
+    }
+
+    fn sort(&mut self, col_ix: usize, order: SortOrder, _: &mut ViewContext<Table<Self>>) {
+        self.companies.sort_by(|a, b| {
+            let ordering = match col_ix {
+                0 => a.name.cmp(&b.name),
+                1 => a.industry.cmp(&b.industry),
+                2 => a.last_done.total_cmp(&b.last_done),
+                _ => a.change_percent().total_cmp(&b.change_percent()),
+            };
+            match order {
+                SortOrder::Ascending => ordering,
+                SortOrder::Descending => ordering.reverse(),
+            }
+        });
+    }
+}
+
+pub struct TableStory {
+    table: View<Table<CompanyTableDelegate>>,
+    multiple: bool,
+    benchmark: bool,
+    selected_count: usize,
+    _subscription: Subscription,
+    _update_task: Task<()>,
+}
+
+impl TableStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let (table, _subscription) = Self::build_table(ROWS, SelectionMode::Single, cx);
+
+        // Update the prices like ListStory does.
+        let _update_task = cx.spawn(move |this, mut cx| async move {
+            loop {
+                Timer::after(Duration::from_secs_f64(0.5)).await;
+                if this
+                    .update(&mut cx, |this, cx| this.update_prices(cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        Self {
+            table,
+            multiple: false,
+            benchmark: false,
+            selected_count: 0,
+            _subscription,
+            _update_task,
+        }
+    }
+
+    fn build_table(
+        rows: usize,
+        mode: SelectionMode,
+        cx: &mut ViewContext<Self>,
+    ) -> (View<Table<CompanyTableDelegate>>, Subscription) {
+        let companies = (0..rows).map(|_| random_company()).collect();
+        let table = cx
+            .new_view(|cx| Table::new(CompanyTableDelegate { companies }, cx).selection_mode(mode));
+        let subscription = cx.subscribe(&table, |this, _, event: &TableEvent, cx| {
+            let TableEvent::SelectionChanged(rows) = event;
+            this.selected_count = rows.len();
+            cx.notify();
+        });
+        (table, subscription)
+    }
+
+    /// Switch between the default rows and the benchmark ones, with a new table.
+    fn set_benchmark(&mut self, benchmark: bool, cx: &mut ViewContext<Self>) {
+        self.benchmark = benchmark;
+        let rows = if benchmark { BENCHMARK_ROWS } else { ROWS };
+        let mode = if self.multiple {
+            SelectionMode::Multiple
+        } else {
+            SelectionMode::Single
+        };
+        (self.table, self._subscription) = Self::build_table(rows, mode, cx);
+        self.selected_count = 0;
+        cx.notify();
+    }
+
+    /// Move the prices of a random sample of the companies.
+    fn update_prices(&mut self, cx: &mut ViewContext<Self>) {
+        self.table.update(cx, |table, cx| {
+            let companies = &mut table.delegate_mut().companies;
+            let count = companies.len();
+            if count == 0 {
+                return;
+            }
+            for _ in 0..count / UPDATE_RATIO {
+                companies[(0..count).fake::<usize>()].random_update();
+            }
+            cx.notify();
+        });
+    }
+}
+
+impl Render for TableStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_4()
+                    .items_center()
+                    .child(
+                        Switch::new("multiple-selection")
+                            .checked(self.multiple)
+                            .label("Multiple selection")
+                            .on_click(cx.listener(|this, checked: &bool, cx| {
+                                this.multiple = *checked;
+                                let mode = if *checked {
+                                    SelectionMode::Multiple
+                                } else {
+                                    SelectionMode::Single
+                                };
+                                this.table
+                                    .update(cx, |table, cx| table.set_selection_mode(mode, cx));
+                            })),
+                    )
+                    .child(
+                        Switch::new("benchmark")
+                            .checked(self.benchmark)
+                            .label(format!("Benchmark, {} rows", BENCHMARK_ROWS))
+                            .on_click(cx.listener(|this, checked: &bool, cx| {
+                                this.set_benchmark(*checked, cx)
+                            })),
+                    )
+                    .child(format!(
+                        "{} rows, {} selected",
+                        self.table.read(cx).delegate().rows_count(),
+                        self.selected_count
+                    )),
+            )
+            .child(div().flex_1().w_full().child(self.table.clone()))
+    }
+}
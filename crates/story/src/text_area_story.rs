@@ -0,0 +1,131 @@
+use components::text_area::TextArea;
+use gpui::{
+    IntoElement, ParentElement as _, Render, SharedString, Styled, View, ViewContext,
+    VisualContext, WindowContext,
+};
+use ti::{h_flex, input::InputEvent, v_flex};
+
+use crate::section;
+
+pub struct TextAreaStory {
+    auto_grow: View<TextArea>,
+    fixed: View<TextArea>,
+    no_wrap: View<TextArea>,
+    disabled: View<TextArea>,
+    last_event: Option<SharedString>,
+}
+
+impl TextAreaStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let auto_grow = cx.new_view(|cx| {
+            TextArea::new(cx)
+                .rows(2)
+                .auto_grow(6)
+                .placeholder("Grows up to 6 rows, then scrolls...")
+        });
+        let fixed = cx.new_view(|cx| {
+            let mut text_area = TextArea::new(cx).rows(4);
+            text_area.set_text(
+                "A text area with 4 rows.\nLong lines are wrapped at the width of the text area, \
+                 and the content scrolls once it is taller than the text area.\n\n\
+                 Press cmd-enter to submit.",
+                cx,
+            );
+            text_area
+        });
+        let no_wrap = cx.new_view(|cx| {
+            let mut text_area = TextArea::new(cx).rows(3).soft_wrap(false);
+            text_area.set_text(
+                "Soft wrap is disabled, so this long line is clipped at the right edge of the text area instead of wrapping.",
+                cx,
+            );
+            text_area
+        });
+        let disabled = cx.new_view(|cx| {
+            let mut text_area = TextArea::new(cx).rows(2);
+            text_area.set_text("This is a disabled text area.", cx);
+            text_area.set_disabled(true, cx);
+            text_area
+        });
+
+        for text_area in [&auto_grow, &fixed, &no_wrap] {
+            cx.subscribe(text_area, Self::on_input_event).detach();
+            // Follow the cursor position.
+            cx.observe(text_area, |_, _, cx| cx.notify()).detach();
+        }
+
+        Self {
+            auto_grow,
+            fixed,
+            no_wrap,
+            disabled,
+            last_event: None,
+        }
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextArea>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.last_event = Some(
+            match event {
+                InputEvent::Change(text) => format!("Change: {} characters", text.chars().count()),
+                InputEvent::PressEnter => "PressEnter".to_string(),
+                InputEvent::Focus => "Focus".to_string(),
+                InputEvent::Blur => "Blur".to_string(),
+            }
+            .into(),
+        );
+        cx.notify();
+    }
+
+    fn position_label(text_area: &View<TextArea>, cx: &ViewContext<Self>) -> String {
+        let (line, column) = text_area.read(cx).cursor_position();
+        format!("Ln {}, Col {}", line + 1, column + 1)
+    }
+}
+
+impl Render for TextAreaStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .p_4()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(
+                        section("Auto Grow", cx)
+                            .child(self.auto_grow.clone())
+                            .child(Self::position_label(&self.auto_grow, cx)),
+                    )
+                    .child(
+                        section("Fixed Rows", cx)
+                            .child(self.fixed.clone())
+                            .child(Self::position_label(&self.fixed, cx)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(
+                        section("No Soft Wrap", cx)
+                            .child(self.no_wrap.clone())
+                            .child(Self::position_label(&self.no_wrap, cx)),
+                    )
+                    .child(section("Disabled", cx).child(self.disabled.clone())),
+            )
+            .child(format!(
+                "Last event: {}",
+                self.last_event.clone().unwrap_or_else(|| "None".into())
+            ))
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::NaiveTime;
+use components::time_picker::{format_time, TimePicker, TimePickerEvent};
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, Styled, View, ViewContext, VisualContext,
+    WindowContext,
+};
+use ti::{h_flex, v_flex};
+
+use crate::section;
+
+fn format_value(value: Option<NaiveTime>) -> String {
+    value
+        .map(|time| format_time(time, false))
+        .unwrap_or_else(|| "None".to_string())
+}
+
+pub struct TimePickerStory {
+    locale: View<TimePicker>,
+    hour_12: View<TimePicker>,
+    hour_24: View<TimePicker>,
+    locale_value: Option<NaiveTime>,
+    hour_12_value: Option<NaiveTime>,
+    hour_24_value: Option<NaiveTime>,
+}
+
+impl TimePickerStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let locale = cx.new_view(TimePicker::new);
+        let hour_12 = cx.new_view(|cx| {
+            let mut picker = TimePicker::new(cx).hour_12(true).minute_step(15);
+            picker.set_value(NaiveTime::from_hms_opt(14, 30, 0), cx);
+            picker
+        });
+        let hour_24 = cx.new_view(|cx| TimePicker::new(cx).hour_12(false).minute_step(1));
+
+        cx.subscribe(&locale, |this, _, event: &TimePickerEvent, cx| {
+            let TimePickerEvent::Change(value) = event;
+            this.locale_value = *value;
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&hour_12, |this, _, event: &TimePickerEvent, cx| {
+            let TimePickerEvent::Change(value) = event;
+            this.hour_12_value = *value;
+            cx.notify();
+        })
+        .detach();
+        cx.subscribe(&hour_24, |this, _, event: &TimePickerEvent, cx| {
+            let TimePickerEvent::Change(value) = event;
+            this.hour_24_value = *value;
+            cx.notify();
+        })
+        .detach();
+
+        let hour_12_value = hour_12.read(cx).value();
+        Self {
+            locale,
+            hour_12,
+            hour_24,
+            locale_value: None,
+            hour_12_value,
+            hour_24_value: None,
+        }
+    }
+}
+
+impl Render for TimePickerStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().size_full().p_4().gap_3().child(
+            h_flex()
+                .gap_3()
+                .items_start()
+                .child(
+                    section("Locale clock", cx)
+                        .child(v_flex().w(px(180.)).child(self.locale.clone()))
+                        .child(format!("Value: {}", format_value(self.locale_value))),
+                )
+                .child(
+                    section("12-hour, every 15 minutes", cx)
+                        .child(v_flex().w(px(180.)).child(self.hour_12.clone()))
+                        .child(format!("Value: {}", format_value(self.hour_12_value))),
+                )
+                .child(
+                    section("24-hour, every minute", cx)
+                        .child(v_flex().w(px(180.)).child(self.hour_24.clone()))
+                        .child(format!("Value: {}", format_value(self.hour_24_value))),
+                ),
+        )
+    }
+}
@@ -488,3 +488,51 @@ impl Render for Dock {
             .when(self.resizeable, |this| this.child(create_resize_handle()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+    use crate::test::{build_workspace, TestPanel};
+
+    #[gpui::test]
+    fn test_remove_panel(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let panels = workspace.update(cx, |workspace, cx| {
+            (0..3)
+                .map(|_| {
+                    let panel = cx.new_view(|cx| TestPanel::new(DockPosition::Left, cx));
+                    workspace.add_panel(panel.clone(), cx);
+                    panel
+                })
+                .collect::<Vec<_>>()
+        });
+        let dock = workspace.update(cx, |workspace, _| workspace.left_dock().clone());
+
+        dock.update(cx, |dock, cx| {
+            assert_eq!(dock.panels_len(), 3);
+            assert_eq!(dock.active_panel_index(), 2);
+
+            dock.activate_panel(1, cx);
+            assert!(panels[1].read(cx).active);
+            assert!(!panels[2].read(cx).active);
+
+            // Removing a panel before the active one keeps the same panel active.
+            dock.remove_panel(&panels[0], cx);
+            assert_eq!(dock.panels_len(), 2);
+            assert_eq!(dock.active_panel_index(), 0);
+            assert_eq!(
+                dock.active_panel().map(|panel| panel.id()),
+                Some(panels[1].entity_id())
+            );
+            assert!(dock.is_open());
+
+            // Removing the active panel closes the dock.
+            dock.remove_panel(&panels[1], cx);
+            assert_eq!(dock.panels_len(), 1);
+            assert_eq!(dock.active_panel_index(), 0);
+            assert!(!dock.is_open());
+        });
+    }
+}
@@ -32,6 +32,11 @@ pub trait Item: FocusableView + EventEmitter<Self::Event> {
         gpui::Empty.into_any()
     }
 
+    /// Returns the plain text title for the tab, used where the tab content can't be rendered.
+    fn tab_title(&self, _: &AppContext) -> Option<SharedString> {
+        None
+    }
+
     /// Returns the tooltip for the tab.
     fn tab_tooltip(&self, _: &AppContext) -> Option<SharedString> {
         None
@@ -92,6 +97,7 @@ pub trait ItemHandle: 'static + Send {
         handler: Box<dyn Fn(ItemEvent, &mut WindowContext)>,
     ) -> gpui::Subscription;
     fn focus_handle(&self, cx: &WindowContext) -> FocusHandle;
+    fn tab_title(&self, cx: &AppContext) -> Option<SharedString>;
     fn tab_tooltip(&self, cx: &AppContext) -> Option<SharedString>;
     fn tab_description(&self, detail: usize, cx: &AppContext) -> Option<SharedString>;
     fn tab_content(&self, params: TabContentParams, cx: &WindowContext) -> AnyElement;
@@ -152,6 +158,10 @@ impl<T: Item> ItemHandle for View<T> {
         self.focus_handle(cx)
     }
 
+    fn tab_title(&self, cx: &AppContext) -> Option<SharedString> {
+        self.read(cx).tab_title(cx)
+    }
+
     fn tab_tooltip(&self, cx: &AppContext) -> Option<SharedString> {
         self.read(cx).tab_tooltip(cx)
     }
@@ -204,9 +214,8 @@ impl<T: Item> ItemHandle for View<T> {
             .insert(self.item_id(), pane.downgrade())
             .is_none()
         {
-            let mut event_subscription = Some(cx.subscribe(
-                self,
-                move |workspace, item: View<T>, event, cx| {
+            let event_subscription =
+                cx.subscribe(self, move |workspace, item: View<T>, event, cx| {
                     let pane = if let Some(pane) = workspace
                         .panes_by_item
                         .get(&item.item_id())
@@ -233,15 +242,19 @@ impl<T: Item> ItemHandle for View<T> {
 
                         _ => {}
                     });
-                },
-            ));
+                });
 
             let item_id = self.item_id();
-            cx.observe_release(self, move |workspace, _, _| {
+            let release_subscription = cx.observe_release(self, move |workspace, _, _| {
                 workspace.panes_by_item.remove(&item_id);
-                event_subscription.take();
-            })
-            .detach();
+                workspace.item_subscriptions.remove(&item_id);
+            });
+
+            // Dropped when the item leaves the workspace, so a moved item is only observed by its
+            // new workspace.
+            workspace
+                .item_subscriptions
+                .insert(item_id, vec![event_subscription, release_subscription]);
         }
 
         // cx.defer(|workspace, cx| {
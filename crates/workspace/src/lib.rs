@@ -2,9 +2,13 @@ pub mod dock;
 pub mod item;
 pub mod pane;
 pub mod pane_group;
+#[cfg(any(test, feature = "test-support"))]
+pub mod test;
 mod title_bar;
 mod util;
 mod workspace;
+mod workspace_store;
 
 pub use title_bar::TitleBar;
 pub use workspace::*;
+pub use workspace_store::{LastWindowClosed, WorkspaceStore};
@@ -2,7 +2,7 @@ use std::{
     any::Any,
     cmp,
     collections::{BTreeSet, HashMap},
-    fmt, mem,
+    fmt, iter, mem,
     ops::ControlFlow,
     rc::Rc,
     sync::Arc,
@@ -10,27 +10,32 @@ use std::{
 
 use anyhow::Result;
 use gpui::{
-    actions, div, impl_actions, prelude::FluentBuilder as _, px, AppContext, DefiniteLength,
-    DragMoveEvent, Element as _, EntityId, EventEmitter, FocusHandle, FocusOutEvent, FocusableView,
-    InteractiveElement as _, IntoElement, KeyContext, ParentElement, Pixels, Point, Render,
-    ScrollHandle, StatefulInteractiveElement, Styled, Subscription, Task, View, ViewContext,
-    VisualContext as _, WeakFocusHandle, WeakView, WindowContext,
+    actions, canvas, div, impl_actions, prelude::FluentBuilder as _, px, transparent_black,
+    AnyElement, AppContext, Bounds, DefiniteLength, DragMoveEvent, Element as _, EntityId,
+    EventEmitter, FocusHandle, FocusOutEvent, FocusableView, InteractiveElement as _, IntoElement,
+    KeyContext, MouseButton, MouseUpEvent, ParentElement, Pixels, Point, Render, ScrollHandle,
+    ScrollWheelEvent, SharedString, StatefulInteractiveElement, Styled, Subscription, Task, View,
+    ViewContext, VisualContext as _, WeakFocusHandle, WeakView, WindowContext,
 };
 use serde::Deserialize;
 
 use crate::util::ResultExt;
 use ti::{
+    button::Button,
+    h_flex,
     label::Label,
+    popup_menu::PopupMenuExt as _,
     tab::{Tab, TabBar},
     theme::{ActiveTheme, Colorize as _},
     tooltip::Tooltip,
-    v_flex, Icon, IconName, Selectable, StyledExt,
+    v_flex, Disableable as _, Icon, IconName, Selectable, Sizable as _, StyledExt,
 };
 
 use super::{
     item::{ItemHandle, TabContentParams},
     pane_group::SplitDirection,
     workspace::Workspace,
+    WorkspaceStore,
 };
 
 #[derive(Clone, Deserialize, PartialEq, Debug)]
@@ -121,6 +126,25 @@ impl Render for DraggedTab {
     }
 }
 
+/// How the tabs of a [`Pane`] are laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabBarMode {
+    /// A single row of tabs, scrolled horizontally when they don't fit.
+    #[default]
+    Scroll,
+    /// Tabs wrap onto multiple rows, the row of the active tab stays next to the content.
+    MultiRow,
+    /// Tabs are listed vertically in a resizable strip on the left of the content.
+    Vertical,
+}
+
+const MIN_VERTICAL_TAB_LIST_WIDTH: Pixels = Pixels(80.);
+const VERTICAL_TAB_LIST_RESIZE_HANDLE_SIZE: Pixels = Pixels(4.);
+
+/// The resize handle of a pane's vertical tab list, carrying the id of the pane.
+#[derive(Clone, Render)]
+pub(crate) struct DraggedTabList(EntityId);
+
 /// A pane
 pub struct Pane {
     pub(crate) workspace: WeakView<Workspace>,
@@ -136,7 +160,14 @@ pub struct Pane {
         Option<Arc<dyn Fn(&mut Pane, &dyn Any, &mut ViewContext<Pane>) -> ControlFlow<(), ()>>>,
     can_split: bool,
     should_display_tab_bar: Rc<dyn Fn(&ViewContext<Pane>) -> bool>,
+    tab_bar_mode: TabBarMode,
     tab_bar_scroll_handle: ScrollHandle,
+    /// The tab widths and available width of the last frame, used to break tabs into rows.
+    tab_widths: HashMap<EntityId, Pixels>,
+    tab_bar_width: Pixels,
+    vertical_tab_list_width: Pixels,
+    /// The tab of this pane being dragged, used to notice drops outside the window.
+    dragged_tab: Option<DraggedTab>,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -170,7 +201,12 @@ impl Pane {
             custom_drop_handle: None,
             can_split: true,
             should_display_tab_bar: Rc::new(|_| true),
+            tab_bar_mode: TabBarMode::default(),
             tab_bar_scroll_handle: ScrollHandle::new(),
+            tab_widths: HashMap::new(),
+            tab_bar_width: px(0.),
+            vertical_tab_list_width: px(200.),
+            dragged_tab: None,
             _subscriptions: subscriptions,
         }
     }
@@ -187,6 +223,15 @@ impl Pane {
         self.should_display_tab_bar = Rc::new(f);
     }
 
+    pub fn set_tab_bar_mode(&mut self, mode: TabBarMode, cx: &mut ViewContext<Self>) {
+        self.tab_bar_mode = mode;
+        cx.notify();
+    }
+
+    pub fn tab_bar_mode(&self) -> TabBarMode {
+        self.tab_bar_mode
+    }
+
     pub fn set_custom_drop_handle<F>(&mut self, cx: &mut ViewContext<Self>, handle: F)
     where
         F: 'static + Fn(&mut Pane, &dyn Any, &mut ViewContext<Pane>) -> ControlFlow<(), ()>,
@@ -259,6 +304,7 @@ impl Pane {
         }
 
         let item = self.items.remove(item_index);
+        self.tab_widths.remove(&item.item_id());
         cx.emit(Event::RemoveItem {
             item_id: item.item_id(),
         });
@@ -606,6 +652,54 @@ impl Pane {
             .log_err();
     }
 
+    fn handle_tab_drag_move(
+        &mut self,
+        event: &DragMoveEvent<DraggedTab>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let dragged_tab = event.drag(cx);
+        if dragged_tab.pane == *cx.view() {
+            self.dragged_tab = Some(dragged_tab.clone());
+        }
+    }
+
+    /// Move a tab released outside the window into the window under the cursor,
+    /// or tear it off into a new window.
+    fn handle_mouse_up_out(&mut self, event: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        let Some(dragged_tab) = self.dragged_tab.take() else {
+            return;
+        };
+        let viewport = Bounds::new(Point::default(), cx.viewport_size());
+        if !cx.has_active_drag() || viewport.contains(&event.position) {
+            return;
+        }
+
+        let position = cx.bounds().origin + event.position;
+        let target = WorkspaceStore::workspace_at_position(position, cx.window_handle(), cx);
+        let from_pane = dragged_tab.pane;
+        let item_id = dragged_tab.item.item_id();
+        self.workspace
+            .update(cx, |_, cx| {
+                cx.defer(move |workspace, cx| {
+                    if let Some((window, target)) = target {
+                        workspace
+                            .move_item_to_window(
+                                from_pane,
+                                item_id,
+                                window,
+                                target,
+                                Some(position),
+                                cx,
+                            )
+                            .log_err();
+                    } else {
+                        workspace.tear_off_item(from_pane, item_id, position, cx);
+                    }
+                });
+            })
+            .log_err();
+    }
+
     fn focus_in(&mut self, cx: &mut ViewContext<Self>) {
         if !self.was_focused {
             self.was_focused = true;
@@ -666,21 +760,7 @@ impl Pane {
             .px(px(5.))
             .prefix(div().size(px(13.)).into_any_element())
             .gap_1p5()
-            .suffix(
-                div()
-                    .id("close-tab")
-                    .p(px(0.))
-                    .rounded_sm()
-                    .invisible()
-                    .child(Icon::new(IconName::Close).size(px(12.)))
-                    .hover(|this| this.bg(cx.theme().accent.darken(0.1)))
-                    .active(|this| this.bg(cx.theme().accent.darken(0.2)))
-                    .on_click(cx.listener(move |pane, _, cx| {
-                        pane.close_item_by_id(item_id, cx).detach_and_log_err(cx);
-                    }))
-                    .group_hover("tab", |this| this.visible())
-                    .into_any(),
-            )
+            .suffix(self.render_close_tab_button(item_id, "tab", cx))
             .selected(is_active)
             .on_click(
                 cx.listener(move |pane: &mut Self, _, cx| pane.activate_item(ix, true, true, cx)),
@@ -714,6 +794,149 @@ impl Pane {
             })
     }
 
+    fn render_close_tab_button(
+        &self,
+        item_id: EntityId,
+        group: &'static str,
+        cx: &mut ViewContext<'_, Pane>,
+    ) -> AnyElement {
+        div()
+            .id("close-tab")
+            .p(px(0.))
+            .rounded_sm()
+            .invisible()
+            .child(Icon::new(IconName::Close).size(px(12.)))
+            .hover(|this| this.bg(cx.theme().accent.darken(0.1)))
+            .active(|this| this.bg(cx.theme().accent.darken(0.2)))
+            .on_click(cx.listener(move |pane, _, cx| {
+                pane.close_item_by_id(item_id, cx).detach_and_log_err(cx);
+            }))
+            .group_hover(group, |this| this.visible())
+            .into_any()
+    }
+
+    fn render_vertical_tab(
+        &self,
+        ix: usize,
+        item: &Box<dyn ItemHandle>,
+        detail: usize,
+        cx: &mut ViewContext<'_, Pane>,
+    ) -> impl IntoElement {
+        let is_active = ix == self.active_item_index;
+
+        let label = item.tab_content(
+            TabContentParams {
+                detail: Some(detail),
+                selected: is_active,
+            },
+            cx,
+        );
+
+        let item_id = item.item_id();
+
+        h_flex()
+            .id(ix)
+            .group("vertical-tab")
+            .flex_none()
+            .w_full()
+            .h_7()
+            .px_2()
+            .gap_1p5()
+            .border_t_2()
+            .border_color(transparent_black())
+            .map(|this| {
+                if is_active {
+                    this.bg(cx.theme().list_active)
+                } else {
+                    this.hover(|this| this.bg(cx.theme().accent))
+                }
+            })
+            .child(div().flex_1().overflow_hidden().child(label))
+            .child(self.render_close_tab_button(item_id, "vertical-tab", cx))
+            .on_click(
+                cx.listener(move |pane: &mut Self, _, cx| pane.activate_item(ix, true, true, cx)),
+            )
+            .on_drag(
+                DraggedTab {
+                    item: item.boxed_clone(),
+                    pane: cx.view().clone(),
+                    detail,
+                    is_active,
+                    ix,
+                },
+                |tab, cx| cx.new_view(|_| tab.clone()),
+            )
+            .drag_over::<DraggedTab>(|tab, _, cx| tab.border_color(cx.theme().drag_border))
+            .drag_over::<DraggedSelection>(|tab, _, cx| tab.bg(cx.theme().drop_target))
+            .when_some(self.can_drop_predicate.clone(), |this, p| {
+                this.can_drop(move |a, cx| p(a, cx))
+            })
+            .on_drop(cx.listener(move |this, dragged_tab: &DraggedTab, cx| {
+                this.drag_split_direction = None;
+                this.handle_tab_drop(dragged_tab, ix, cx)
+            }))
+            .when_some(item.tab_tooltip(cx), |tab, text| {
+                tab.tooltip(move |cx| Tooltip::new(text.clone(), cx))
+            })
+    }
+
+    fn render_vertical_tab_list(&mut self, cx: &mut ViewContext<'_, Pane>) -> impl IntoElement {
+        let pane_id = cx.entity_id();
+
+        v_flex()
+            .relative()
+            .flex_none()
+            .h_full()
+            .w(self.vertical_tab_list_width)
+            .bg(cx.theme().tab_bar)
+            .border_r_1()
+            .border_color(cx.theme().border)
+            .on_drag_move(
+                cx.listener(move |pane, e: &DragMoveEvent<DraggedTabList>, cx| {
+                    if e.drag(cx).0 != pane_id {
+                        return;
+                    }
+                    let width =
+                        (e.event.position.x - e.bounds.left()).max(MIN_VERTICAL_TAB_LIST_WIDTH);
+                    if pane.vertical_tab_list_width != width {
+                        pane.vertical_tab_list_width = width;
+                        cx.notify();
+                    }
+                }),
+            )
+            .child(
+                v_flex()
+                    .id("vertical-tab-list")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .children(
+                        self.items
+                            .iter()
+                            .enumerate()
+                            .zip(tab_details(&self.items, cx))
+                            .map(|((ix, item), detail)| {
+                                self.render_vertical_tab(ix, item, detail, cx)
+                            }),
+                    )
+                    .child(self.render_tab_bar_drop_target(cx)),
+            )
+            .child(
+                div()
+                    .id("vertical-tab-list-resize-handle")
+                    .absolute()
+                    .top_0()
+                    .right(-VERTICAL_TAB_LIST_RESIZE_HANDLE_SIZE / 2.)
+                    .h_full()
+                    .w(VERTICAL_TAB_LIST_RESIZE_HANDLE_SIZE)
+                    .cursor_col_resize()
+                    .on_drag(DraggedTabList(pane_id), |handle, cx| {
+                        cx.stop_propagation();
+                        cx.new_view(|_| handle.clone())
+                    })
+                    .occlude(),
+            )
+    }
+
     fn render_tab_bar(&mut self, cx: &mut ViewContext<'_, Pane>) -> impl IntoElement {
         //     let navigate_backward = IconButton::new("navigate_backward", IconName::ArrowLeft)
         //     .shape(IconButtonShape::Square)
@@ -735,29 +958,237 @@ impl Pane {
         //     .disabled(!self.can_navigate_forward())
         //     .tooltip(|cx| Tooltip::for_action("Go Forward", &GoForward, cx));
 
-        TabBar::new("tab-bar")
-            .track_scroll(self.tab_bar_scroll_handle.clone())
-            .children(
-                self.items
-                    .iter()
-                    .enumerate()
-                    .zip(tab_details(&self.items, cx))
-                    .map(|((ix, item), detail)| self.render_tab(ix, item, detail, cx)),
+        let can_scroll_left = self.tab_bar_scroll_handle.offset().x < px(0.);
+        let can_scroll_right =
+            self.max_tab_bar_scroll_offset() + self.tab_bar_scroll_handle.offset().x > px(0.);
+
+        h_flex()
+            .w_full()
+            .bg(cx.theme().tab_bar)
+            .on_scroll_wheel(cx.listener(Self::handle_tab_bar_scroll_wheel))
+            .child(
+                Button::new("scroll-tabs-left", cx)
+                    .icon(IconName::ChevronLeft)
+                    .ghost()
+                    .small()
+                    .disabled(!can_scroll_left)
+                    .on_click(cx.listener(|pane, _, cx| {
+                        let step = pane.tab_bar_scroll_handle.bounds().size.width / 2.;
+                        pane.scroll_tab_bar(step, cx);
+                    })),
             )
             .child(
-                div()
-                    .id("tab-bar-drop-target")
-                    .min_w_6()
-                    .child("")
-                    .h_full()
-                    .flex_grow()
-                    .drag_over::<DraggedTab>(|bar, _, cx| bar.bg(cx.theme().drop_target))
-                    .drag_over::<DraggedSelection>(|bar, _, cx| bar.bg(cx.theme().drop_target))
-                    .on_drop(cx.listener(move |this, dragged_tab: &DraggedTab, cx| {
-                        this.drag_split_direction = None;
-                        this.handle_tab_drop(dragged_tab, this.items.len(), cx)
+                div().flex_1().overflow_hidden().child(
+                    TabBar::new("tab-bar")
+                        .track_scroll(self.tab_bar_scroll_handle.clone())
+                        .children(
+                            self.items
+                                .iter()
+                                .enumerate()
+                                .zip(tab_details(&self.items, cx))
+                                .map(|((ix, item), detail)| self.render_tab(ix, item, detail, cx)),
+                        )
+                        .child(self.render_tab_bar_drop_target(cx)),
+                ),
+            )
+            .child(
+                Button::new("scroll-tabs-right", cx)
+                    .icon(IconName::ChevronRight)
+                    .ghost()
+                    .small()
+                    .disabled(!can_scroll_right)
+                    .on_click(cx.listener(|pane, _, cx| {
+                        let step = pane.tab_bar_scroll_handle.bounds().size.width / 2.;
+                        pane.scroll_tab_bar(-step, cx);
                     })),
             )
+            .child(self.render_tab_bar_menu(cx))
+    }
+
+    fn render_multi_row_tab_bar(&mut self, cx: &mut ViewContext<'_, Pane>) -> impl IntoElement {
+        let details = tab_details(&self.items, cx);
+        let rows = self.tab_rows();
+        let last_row = rows.len() - 1;
+        let active_row = rows
+            .iter()
+            .position(|row| row.contains(&self.active_item_index))
+            .unwrap_or(last_row);
+        // Keep the row of the active tab adjacent to the content.
+        let row_order = (0..rows.len())
+            .filter(|row_ix| *row_ix != active_row)
+            .chain(iter::once(active_row))
+            .collect::<Vec<_>>();
+        let last_rendered_row = row_order.len() - 1;
+        let view = cx.view().downgrade();
+
+        v_flex()
+            .w_full()
+            .relative()
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |pane, cx| {
+                            if pane.tab_bar_width != bounds.size.width {
+                                pane.tab_bar_width = bounds.size.width;
+                                cx.notify();
+                            }
+                        })
+                        .ok();
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .children(row_order.into_iter().enumerate().map(|(order_ix, row_ix)| {
+                TabBar::new(("tab-bar-row", row_ix))
+                    .children(rows[row_ix].iter().map(|ix| {
+                        self.render_measured_tab(*ix, &self.items[*ix], details[*ix], cx)
+                    }))
+                    // The drop target ends the row rendered last, next to the content.
+                    .when(order_ix == last_rendered_row, |this| {
+                        this.child(self.render_tab_bar_drop_target(cx))
+                    })
+            }))
+    }
+
+    /// Break the tabs into rows that fit the tab bar, using the sizes of the last frame.
+    fn tab_rows(&self) -> Vec<Vec<usize>> {
+        tab_rows(
+            self.items.iter().map(|item| {
+                self.tab_widths
+                    .get(&item.item_id())
+                    .copied()
+                    .unwrap_or(px(0.))
+            }),
+            self.tab_bar_width,
+        )
+    }
+
+    fn render_measured_tab(
+        &self,
+        ix: usize,
+        item: &Box<dyn ItemHandle>,
+        detail: usize,
+        cx: &mut ViewContext<'_, Pane>,
+    ) -> impl IntoElement {
+        let item_id = item.item_id();
+        let view = cx.view().downgrade();
+
+        div()
+            .relative()
+            .flex_none()
+            .child(self.render_tab(ix, item, detail, cx))
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |pane, cx| {
+                            let width = bounds.size.width;
+                            if pane.tab_widths.insert(item_id, width) != Some(width) {
+                                cx.notify();
+                            }
+                        })
+                        .ok();
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+    }
+
+    fn render_tab_bar_drop_target(&self, cx: &mut ViewContext<'_, Pane>) -> impl IntoElement {
+        div()
+            .id("tab-bar-drop-target")
+            .min_w_6()
+            .child("")
+            .h_full()
+            .flex_grow()
+            .drag_over::<DraggedTab>(|bar, _, cx| bar.bg(cx.theme().drop_target))
+            .drag_over::<DraggedSelection>(|bar, _, cx| bar.bg(cx.theme().drop_target))
+            .on_drop(cx.listener(move |this, dragged_tab: &DraggedTab, cx| {
+                this.drag_split_direction = None;
+                this.handle_tab_drop(dragged_tab, this.items.len(), cx)
+            }))
+    }
+
+    /// A dropdown listing every item, to reach tabs that are scrolled out of view.
+    fn render_tab_bar_menu(&self, cx: &mut ViewContext<'_, Pane>) -> impl IntoElement {
+        let active_item_index = self.active_item_index;
+        let titles = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(ix, item)| {
+                item.tab_title(cx)
+                    .or_else(|| item.tab_tooltip(cx))
+                    .unwrap_or_else(|| format!("Tab {}", ix + 1).into())
+            })
+            .collect::<Vec<SharedString>>();
+
+        div()
+            // Make sure the menu actions are dispatched to this pane.
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|pane, _, cx| {
+                    if !pane.has_focus(cx) {
+                        pane.focus(cx);
+                    }
+                }),
+            )
+            .child(
+                Button::new("more-tabs", cx)
+                    .icon(IconName::ChevronDown)
+                    .ghost()
+                    .small()
+                    .popup_menu(move |this, _| {
+                        titles.iter().enumerate().fold(this, |menu, (ix, title)| {
+                            menu.menu_with_check(
+                                title.clone(),
+                                ix == active_item_index,
+                                Box::new(ActivateItem(ix)),
+                            )
+                        })
+                    }),
+            )
+    }
+
+    /// How far the tab bar can scroll, based on the tab bounds of the last frame.
+    fn max_tab_bar_scroll_offset(&self) -> Pixels {
+        let handle = &self.tab_bar_scroll_handle;
+        let (Some(first), Some(last)) = (
+            handle.bounds_for_item(0),
+            self.items
+                .len()
+                .checked_sub(1)
+                .and_then(|ix| handle.bounds_for_item(ix)),
+        ) else {
+            return px(0.);
+        };
+
+        (last.right() - first.left() - handle.bounds().size.width).max(px(0.))
+    }
+
+    fn scroll_tab_bar(&mut self, delta: Pixels, cx: &mut ViewContext<Self>) {
+        let mut offset = self.tab_bar_scroll_handle.offset();
+        offset.x = (offset.x + delta)
+            .min(px(0.))
+            .max(-self.max_tab_bar_scroll_offset());
+        self.tab_bar_scroll_handle.set_offset(offset);
+        cx.notify();
+    }
+
+    fn handle_tab_bar_scroll_wheel(
+        &mut self,
+        event: &ScrollWheelEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        // Horizontal scrolling is handled by the tab bar itself, map the vertical wheel to it.
+        let delta = event.delta.pixel_delta(cx.line_height());
+        if delta.y.abs() > delta.x.abs() {
+            self.scroll_tab_bar(delta.y, cx);
+            cx.stop_propagation();
+        }
     }
 
     pub fn set_zoomed(&mut self, zoomed: bool, cx: &mut ViewContext<Self>) {
@@ -810,6 +1241,24 @@ pub fn tab_details(items: &Vec<Box<dyn ItemHandle>>, cx: &AppContext) -> Vec<usi
     tab_details
 }
 
+/// Break tabs of the given widths into rows no wider than `available`, returning the indices
+/// of the tabs in each row. A tab wider than `available` gets a row of its own.
+fn tab_rows(widths: impl IntoIterator<Item = Pixels>, available: Pixels) -> Vec<Vec<usize>> {
+    let mut rows = vec![vec![]];
+    let mut row_width = px(0.);
+    for (ix, width) in widths.into_iter().enumerate() {
+        let row = rows.last_mut().unwrap();
+        if !row.is_empty() && row_width + width > available {
+            rows.push(vec![ix]);
+            row_width = width;
+        } else {
+            row.push(ix);
+            row_width += width;
+        }
+    }
+    rows
+}
+
 impl FocusableView for Pane {
     fn focus_handle(&self, _cx: &AppContext) -> FocusHandle {
         self.focus_handle.clone()
@@ -833,6 +1282,12 @@ impl Render for Pane {
             .size_full()
             .flex_none()
             .overflow_hidden()
+            .on_drag_move::<DraggedTab>(cx.listener(Self::handle_tab_drag_move))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|pane, _: &MouseUpEvent, _| pane.dragged_tab = None),
+            )
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::handle_mouse_up_out))
             .on_action(cx.listener(|pane, _: &SplitLeft, cx| pane.split(SplitDirection::Left, cx)))
             .on_action(cx.listener(|pane, _: &SplitUp, cx| pane.split(SplitDirection::Up, cx)))
             .on_action(
@@ -894,9 +1349,16 @@ impl Render for Pane {
                     }
                 }),
             )
-            .when(self.active_item().is_some() && display_tab_bar, |pane| {
-                pane.child(self.render_tab_bar(cx))
-            })
+            .when(
+                self.active_item().is_some() && display_tab_bar,
+                |pane| match self.tab_bar_mode {
+                    TabBarMode::Scroll => pane.child(self.render_tab_bar(cx)),
+                    TabBarMode::MultiRow => pane.child(self.render_multi_row_tab_bar(cx)),
+                    TabBarMode::Vertical => {
+                        pane.flex_row().child(self.render_vertical_tab_list(cx))
+                    }
+                },
+            )
             .child({
                 // main content
                 div()
@@ -995,3 +1457,150 @@ pub struct DraggedSelection {
 pub struct SelectedEntry {
     pub entry_id: EntityId,
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+    use crate::test::{active_label, add_test_item, build_workspace, item_labels};
+
+    #[gpui::test]
+    fn test_add_item_after_active_item(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+
+        add_test_item(&pane, "A", cx);
+        add_test_item(&pane, "B", cx);
+        add_test_item(&pane, "C", cx);
+        assert_eq!(item_labels(&pane, cx), ["A", "B", "C"]);
+        assert_eq!(active_label(&pane, cx), Some("C".into()));
+
+        pane.update(cx, |pane, cx| pane.activate_item(0, true, true, cx));
+        add_test_item(&pane, "D", cx);
+        assert_eq!(item_labels(&pane, cx), ["A", "D", "B", "C"]);
+        assert_eq!(active_label(&pane, cx), Some("D".into()));
+        pane.update(cx, |pane, _| assert_eq!(pane.active_item_index(), 1));
+    }
+
+    #[gpui::test]
+    fn test_add_existing_item_moves_it(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+
+        let a = add_test_item(&pane, "A", cx);
+        add_test_item(&pane, "B", cx);
+        let c = add_test_item(&pane, "C", cx);
+        pane.update(cx, |pane, cx| pane.activate_item(0, true, true, cx));
+
+        pane.update(cx, |pane, cx| {
+            pane.add_item(Box::new(c.clone()), true, true, Some(0), cx)
+        });
+        assert_eq!(item_labels(&pane, cx), ["C", "A", "B"]);
+        assert_eq!(active_label(&pane, cx), Some("C".into()));
+
+        pane.update(cx, |pane, cx| {
+            pane.add_item(Box::new(a.clone()), true, true, Some(2), cx)
+        });
+        assert_eq!(item_labels(&pane, cx), ["C", "B", "A"]);
+        assert_eq!(active_label(&pane, cx), Some("A".into()));
+
+        // Adding the active item again without a destination doesn't move it.
+        pane.update(cx, |pane, cx| {
+            pane.add_item(Box::new(a.clone()), true, true, None, cx)
+        });
+        assert_eq!(item_labels(&pane, cx), ["C", "B", "A"]);
+        pane.update(cx, |pane, _| assert_eq!(pane.active_item_index(), 2));
+    }
+
+    #[gpui::test]
+    fn test_remove_item_updates_active_index(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+
+        add_test_item(&pane, "A", cx);
+        add_test_item(&pane, "B", cx);
+        add_test_item(&pane, "C", cx);
+        pane.update(cx, |pane, cx| pane.activate_item(1, true, true, cx));
+
+        // Removing an item before the active one keeps the same item active.
+        pane.update(cx, |pane, cx| pane.remove_item(0, false, cx));
+        assert_eq!(item_labels(&pane, cx), ["B", "C"]);
+        assert_eq!(active_label(&pane, cx), Some("B".into()));
+
+        // Removing the active item activates the previous one.
+        pane.update(cx, |pane, cx| {
+            pane.activate_item(1, true, true, cx);
+            pane.remove_item(1, false, cx);
+        });
+        assert_eq!(item_labels(&pane, cx), ["B"]);
+        assert_eq!(active_label(&pane, cx), Some("B".into()));
+    }
+
+    #[gpui::test]
+    fn test_close_items(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+
+        for label in ["A", "B", "C", "D", "E"] {
+            add_test_item(&pane, label, cx);
+        }
+
+        pane.update(cx, |pane, cx| {
+            pane.close_active_item(&CloseActiveItem, cx)
+                .unwrap()
+                .detach_and_log_err(cx)
+        });
+        cx.run_until_parked();
+        assert_eq!(item_labels(&pane, cx), ["A", "B", "C", "D"]);
+        assert_eq!(active_label(&pane, cx), Some("D".into()));
+
+        pane.update(cx, |pane, cx| {
+            pane.activate_item(1, true, true, cx);
+            pane.close_items_to_the_right(&CloseItemsToTheRight, cx)
+                .unwrap()
+                .detach_and_log_err(cx)
+        });
+        cx.run_until_parked();
+        assert_eq!(item_labels(&pane, cx), ["A", "B"]);
+        assert_eq!(active_label(&pane, cx), Some("B".into()));
+
+        pane.update(cx, |pane, cx| {
+            pane.close_items_to_the_left(&CloseItemsToTheLeft, cx)
+                .unwrap()
+                .detach_and_log_err(cx)
+        });
+        cx.run_until_parked();
+        assert_eq!(item_labels(&pane, cx), ["B"]);
+
+        pane.update(cx, |pane, cx| {
+            pane.close_all_items(&CloseAllItems, cx)
+                .unwrap()
+                .detach_and_log_err(cx)
+        });
+        cx.run_until_parked();
+        assert!(item_labels(&pane, cx).is_empty());
+        assert_eq!(active_label(&pane, cx), None);
+    }
+
+    #[test]
+    fn test_tab_rows() {
+        let rows = |widths: &[f32], available: f32| {
+            tab_rows(widths.iter().map(|width| px(*width)), px(available))
+        };
+
+        // No tabs still make one, empty, row.
+        assert_eq!(rows(&[], 100.), [Vec::<usize>::new()]);
+        assert_eq!(rows(&[30., 30., 30.], 100.), [vec![0, 1, 2]]);
+        // A tab that fits exactly stays in the row.
+        assert_eq!(rows(&[50., 50., 50.], 100.), [vec![0, 1], vec![2]]);
+        assert_eq!(
+            rows(&[60., 30., 20., 90., 10.], 100.),
+            [vec![0, 1], vec![2], vec![3, 4]]
+        );
+        // Tabs wider than the tab bar get a row of their own.
+        assert_eq!(rows(&[150., 20., 150.], 100.), [vec![0], vec![1], vec![2]]);
+        // Before the tab bar is measured, every tab is alone.
+        assert_eq!(rows(&[30., 30.], 0.), [vec![0], vec![1]]);
+    }
+}
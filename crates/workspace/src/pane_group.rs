@@ -1,11 +1,12 @@
 use anyhow::{anyhow, Result};
 use element::pane_axis;
 use gpui::{
-    div, point, size, AnyView, AnyWeakView, Axis, Bounds, Element as _, IntoElement,
-    ParentElement as _, Pixels, Point, StyleRefinement, Styled as _, View, ViewContext,
+    div, point, size, AnyView, AnyWeakView, Axis, Bounds, Element as _, Entity as _, EntityId,
+    IntoElement, ParentElement as _, Pixels, Point, StyleRefinement, Styled as _, View,
+    ViewContext,
 };
 use parking_lot::Mutex;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use ti::StyledExt as _;
 
@@ -102,10 +103,181 @@ impl PaneGroup {
         panes
     }
 
-    #[allow(unused)]
     pub(crate) fn first_pane(&self) -> View<Pane> {
         self.root.first_pane()
     }
+
+    /// Returns a description of the current layout.
+    pub fn layout(&self) -> PaneLayout {
+        self.root.layout()
+    }
+
+    /// Build a group from a layout.
+    ///
+    /// `pane_for` is called for every pane of the layout, in order, and must return the pane
+    /// to place there.
+    pub(crate) fn from_layout(
+        layout: &PaneLayoutSpec,
+        pane_for: &mut impl FnMut(Option<EntityId>) -> Result<View<Pane>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            root: Member::from_layout(layout, pane_for)?,
+        })
+    }
+}
+
+/// The panes of a [`PaneGroup`] and how they are split, with `P` the data of each pane.
+///
+/// [`PaneGroup::layout`] describes the current panes by their entity ids, and a
+/// [`PaneLayoutSpec`] is the shape to build with [`Workspace::set_center_layout`]. Other
+/// data can be attached to the panes with [`PaneLayout::map`], e.g. to save a layout.
+///
+/// Axes are written in lowercase, and `flexes` may be left out for equal sizes:
+///
+/// ```json
+/// { "axis": "horizontal", "flexes": [1, 2], "members": [<pane>, <pane>] }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PaneLayout<P = EntityId> {
+    /// Members arranged along an axis.
+    Axis {
+        #[serde(with = "serde_axis")]
+        axis: Axis,
+        /// One entry per member, or none for equal sizes.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        flexes: Vec<f32>,
+        members: Vec<PaneLayout<P>>,
+    },
+    Pane(P),
+}
+
+/// A layout to build, reusing the panes with an id and adding a new, empty pane for `None`.
+pub type PaneLayoutSpec = PaneLayout<Option<EntityId>>;
+
+impl<P> PaneLayout<P> {
+    /// Split the given members evenly along an axis.
+    pub fn axis(axis: Axis, members: Vec<PaneLayout<P>>) -> Self {
+        Self::Axis {
+            axis,
+            flexes: vec![1.; members.len()],
+            members,
+        }
+    }
+
+    /// Returns the data of the panes in this layout, in order.
+    pub fn panes(&self) -> Vec<&P> {
+        let mut panes = Vec::new();
+        self.collect_panes(&mut panes);
+        panes
+    }
+
+    fn collect_panes<'a>(&'a self, panes: &mut Vec<&'a P>) {
+        match self {
+            PaneLayout::Pane(pane) => panes.push(pane),
+            PaneLayout::Axis { members, .. } => {
+                for member in members {
+                    member.collect_panes(panes);
+                }
+            }
+        }
+    }
+
+    /// The same layout, with the data of each pane replaced by `f`.
+    pub fn map<Q>(&self, f: &mut impl FnMut(&P) -> Q) -> PaneLayout<Q> {
+        match self {
+            PaneLayout::Pane(pane) => PaneLayout::Pane(f(pane)),
+            PaneLayout::Axis {
+                axis,
+                flexes,
+                members,
+            } => PaneLayout::Axis {
+                axis: *axis,
+                flexes: flexes.clone(),
+                members: members.iter().map(|member| member.map(f)).collect(),
+            },
+        }
+    }
+
+    fn validate_axes(&self) -> Result<()> {
+        if let PaneLayout::Axis {
+            flexes, members, ..
+        } = self
+        {
+            if members.len() < 2 {
+                return Err(anyhow!("An axis must have at least 2 members"));
+            }
+            if !flexes.is_empty() && flexes.len() != members.len() {
+                return Err(anyhow!(
+                    "Expected {} flexes, got {}",
+                    members.len(),
+                    flexes.len()
+                ));
+            }
+            if flexes.iter().any(|flex| !flex.is_finite() || *flex <= 0.) {
+                return Err(anyhow!("Flexes must be positive numbers"));
+            }
+            for member in members {
+                member.validate_axes()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PaneLayout {
+    /// Returns the ids of the panes in this layout, in order.
+    pub fn pane_ids(&self) -> Vec<EntityId> {
+        self.panes().into_iter().copied().collect()
+    }
+}
+
+impl PaneLayoutSpec {
+    /// Returns the ids of the existing panes reused by this layout, in order.
+    pub fn pane_ids(&self) -> Vec<EntityId> {
+        self.panes().into_iter().flatten().copied().collect()
+    }
+
+    /// Check the layout is well formed, without building anything.
+    pub fn validate(&self) -> Result<()> {
+        self.validate_axes()?;
+
+        let ids = self.pane_ids();
+        for (ix, id) in ids.iter().enumerate() {
+            if ids[..ix].contains(id) {
+                return Err(anyhow!("Pane {:?} is used more than once", id));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<PaneLayout> for PaneLayoutSpec {
+    fn from(layout: PaneLayout) -> Self {
+        layout.map(&mut |pane_id| Some(*pane_id))
+    }
+}
+
+/// Axes are written in lowercase, as in the layout files of the app.
+mod serde_axis {
+    use gpui::Axis;
+    use serde::{de::Error as _, Deserialize as _, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(axis: &Axis, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(match axis {
+            Axis::Horizontal => "horizontal",
+            Axis::Vertical => "vertical",
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Axis, D::Error> {
+        match String::deserialize(deserializer)?.as_str() {
+            "horizontal" => Ok(Axis::Horizontal),
+            "vertical" => Ok(Axis::Vertical),
+            axis => Err(D::Error::unknown_variant(axis, &["horizontal", "vertical"])),
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -174,6 +346,50 @@ impl Member {
         }
     }
 
+    fn layout(&self) -> PaneLayout {
+        match self {
+            Member::Axis(axis) => PaneLayout::Axis {
+                axis: axis.axis,
+                flexes: axis.flexes.lock().clone(),
+                members: axis.members.iter().map(|member| member.layout()).collect(),
+            },
+            Member::Pane(pane) => PaneLayout::Pane(pane.entity_id()),
+        }
+    }
+
+    fn from_layout(
+        layout: &PaneLayoutSpec,
+        pane_for: &mut impl FnMut(Option<EntityId>) -> Result<View<Pane>>,
+    ) -> Result<Self> {
+        match layout {
+            PaneLayout::Pane(pane_id) => Ok(Member::Pane(pane_for(*pane_id)?)),
+            PaneLayout::Axis {
+                axis,
+                flexes,
+                members,
+            } => {
+                let members = members
+                    .iter()
+                    .map(|member| Member::from_layout(member, pane_for))
+                    .collect::<Result<Vec<_>>>()?;
+
+                // Flexes are relative to each other, normalize them so they sum up to
+                // the number of members as the rendering expects.
+                let flexes = if flexes.is_empty() {
+                    vec![1.; members.len()]
+                } else {
+                    let total = flexes.iter().sum::<f32>();
+                    flexes
+                        .iter()
+                        .map(|flex| flex * members.len() as f32 / total)
+                        .collect()
+                };
+
+                Ok(Member::Axis(PaneAxis::load(*axis, members, Some(flexes))))
+            }
+        }
+    }
+
     fn collect_panes<'a>(&'a self, panes: &mut Vec<&'a View<Pane>>) {
         match self {
             Member::Axis(axis) => {
@@ -206,7 +422,6 @@ impl PaneAxis {
         }
     }
 
-    #[allow(unused)]
     pub fn load(axis: Axis, members: Vec<Member>, flexes: Option<Vec<f32>>) -> Self {
         let flexes = flexes.unwrap_or_else(|| vec![1.; members.len()]);
         debug_assert!(members.len() == flexes.len());
@@ -846,3 +1061,130 @@ mod element {
         (flexes.iter().copied().sum::<f32>() - flexes.len() as f32).abs() < 0.001
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Axis, EntityId};
+
+    use super::{PaneLayout, PaneLayoutSpec};
+
+    fn id(id: u64) -> Option<EntityId> {
+        Some(EntityId::from(id))
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(PaneLayoutSpec::Pane(None).validate().is_ok());
+        assert!(PaneLayoutSpec::axis(
+            Axis::Horizontal,
+            vec![
+                PaneLayout::Pane(id(1)),
+                PaneLayout::axis(
+                    Axis::Vertical,
+                    vec![PaneLayout::Pane(None), PaneLayout::Pane(None)]
+                ),
+            ],
+        )
+        .validate()
+        .is_ok());
+
+        // Flexes may be left out for equal sizes.
+        assert!(PaneLayoutSpec::Axis {
+            axis: Axis::Vertical,
+            flexes: Vec::new(),
+            members: vec![PaneLayout::Pane(None), PaneLayout::Pane(None)],
+        }
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_validate_errors() {
+        let axis = |flexes: Vec<f32>, members: Vec<PaneLayoutSpec>| PaneLayoutSpec::Axis {
+            axis: Axis::Horizontal,
+            flexes,
+            members,
+        };
+
+        // Too few members, at the root or nested.
+        assert!(axis(vec![1.], vec![PaneLayout::Pane(None)])
+            .validate()
+            .is_err());
+        assert!(axis(
+            vec![1., 1.],
+            vec![PaneLayout::Pane(None), axis(Vec::new(), Vec::new())]
+        )
+        .validate()
+        .is_err());
+
+        // Flexes that don't match the members, or are not positive.
+        let members = || vec![PaneLayout::Pane(None), PaneLayout::Pane(None)];
+        assert!(axis(vec![1.], members()).validate().is_err());
+        assert!(axis(vec![1., 0.], members()).validate().is_err());
+        assert!(axis(vec![1., -1.], members()).validate().is_err());
+        assert!(axis(vec![1., f32::NAN], members()).validate().is_err());
+
+        // A pane used twice.
+        assert!(axis(
+            vec![1., 1.],
+            vec![PaneLayout::Pane(id(1)), PaneLayout::Pane(id(1))]
+        )
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_pane_ids() {
+        let layout = PaneLayoutSpec::axis(
+            Axis::Horizontal,
+            vec![
+                PaneLayout::Pane(id(2)),
+                PaneLayout::Pane(None),
+                PaneLayout::Pane(id(1)),
+            ],
+        );
+        assert_eq!(layout.pane_ids(), [id(2).unwrap(), id(1).unwrap()]);
+        assert_eq!(layout.panes(), [&id(2), &None, &id(1)]);
+    }
+
+    #[test]
+    fn test_serde() {
+        let layout: PaneLayout<String> = serde_json::from_str(
+            r#"{
+                "axis": "horizontal",
+                "members": ["a", { "axis": "vertical", "flexes": [1, 2], "members": ["b", "c"] }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            layout,
+            PaneLayout::Axis {
+                axis: Axis::Horizontal,
+                flexes: Vec::new(),
+                members: vec![
+                    PaneLayout::Pane("a".to_string()),
+                    PaneLayout::Axis {
+                        axis: Axis::Vertical,
+                        flexes: vec![1., 2.],
+                        members: vec![
+                            PaneLayout::Pane("b".to_string()),
+                            PaneLayout::Pane("c".to_string()),
+                        ],
+                    },
+                ],
+            }
+        );
+
+        let json = serde_json::to_value(&layout).unwrap();
+        assert_eq!(json["axis"], "horizontal");
+        assert!(json.get("flexes").is_none());
+        assert_eq!(
+            serde_json::from_value::<PaneLayout<String>>(json).unwrap(),
+            layout
+        );
+        assert!(serde_json::from_str::<PaneLayout<String>>(
+            r#"{ "axis": "diagonal", "members": ["a", "b"] }"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use gpui::{
+    div, px, AnyElement, AppContext, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Render, SharedString,
+    TestAppContext, View, ViewContext, VisualContext as _, VisualTestContext, WindowContext,
+};
+use ti::{label::Label, theme::Theme};
+
+use crate::{
+    dock::{DockPosition, Panel, PanelEvent},
+    item::{Item, ItemEvent, TabContentParams},
+    pane::Pane,
+    Workspace, WorkspaceId,
+};
+
+/// An [`Item`] that only renders its label, for use in tests.
+pub struct TestItem {
+    focus_handle: FocusHandle,
+    pub label: SharedString,
+}
+
+impl TestItem {
+    pub fn new(label: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            label: label.into(),
+        }
+    }
+}
+
+impl FocusableView for TestItem {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<ItemEvent> for TestItem {}
+
+impl Item for TestItem {
+    type Event = ItemEvent;
+
+    fn tab_content(&self, _: TabContentParams, _: &WindowContext) -> AnyElement {
+        Label::new(self.label.clone()).into_any_element()
+    }
+
+    fn tab_title(&self, _: &AppContext) -> Option<SharedString> {
+        Some(self.label.clone())
+    }
+
+    fn clone_on_split(
+        &self,
+        _: Option<WorkspaceId>,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<View<Self>> {
+        Some(cx.new_view(|cx| Self::new(self.label.clone(), cx)))
+    }
+
+    fn to_item_events(event: &Self::Event, mut f: impl FnMut(ItemEvent)) {
+        f(*event)
+    }
+}
+
+impl Render for TestItem {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .track_focus(&self.focus_handle)
+            .child(self.label.clone())
+    }
+}
+
+/// A [`Panel`] with a fixed position, for use in tests.
+pub struct TestPanel {
+    focus_handle: FocusHandle,
+    pub position: DockPosition,
+    pub size: Pixels,
+    pub active: bool,
+    pub zoomed: bool,
+}
+
+impl TestPanel {
+    pub fn new(position: DockPosition, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            position,
+            size: px(300.),
+            active: false,
+            zoomed: false,
+        }
+    }
+}
+
+impl FocusableView for TestPanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for TestPanel {}
+
+impl Panel for TestPanel {
+    fn persistent_name() -> &'static str {
+        "test-panel"
+    }
+
+    fn position(&self, _: &WindowContext) -> DockPosition {
+        self.position
+    }
+
+    fn set_position(&mut self, position: DockPosition, cx: &mut ViewContext<Self>) {
+        self.position = position;
+        cx.notify();
+    }
+
+    fn size(&self, _: &WindowContext) -> Pixels {
+        self.size
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, cx: &mut ViewContext<Self>) {
+        self.size = size.unwrap_or(px(300.));
+        cx.notify();
+    }
+
+    fn set_active(&mut self, active: bool, cx: &mut ViewContext<Self>) {
+        self.active = active;
+        cx.notify();
+    }
+
+    fn is_zoomed(&self, _: &WindowContext) -> bool {
+        self.zoomed
+    }
+
+    fn set_zoomed(&mut self, zoomed: bool, cx: &mut ViewContext<Self>) {
+        self.zoomed = zoomed;
+        cx.notify();
+    }
+}
+
+impl Render for TestPanel {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        div().track_focus(&self.focus_handle).child("Test Panel")
+    }
+}
+
+/// Initialize the globals a [`Workspace`] needs to render.
+pub fn init_test(cx: &mut TestAppContext) {
+    cx.update(|cx| {
+        Theme::init(cx);
+    });
+}
+
+/// Open a window with an empty [`Workspace`] as its root.
+pub fn build_workspace(cx: &mut TestAppContext) -> (View<Workspace>, &mut VisualTestContext) {
+    init_test(cx);
+    cx.add_window_view(|cx| Workspace::new(None, cx))
+}
+
+/// Add a new [`TestItem`] to the pane, after its active item.
+pub fn add_test_item(
+    pane: &View<Pane>,
+    label: impl Into<SharedString>,
+    cx: &mut VisualTestContext,
+) -> View<TestItem> {
+    let label = label.into();
+    pane.update(cx, |pane, cx| {
+        let item = cx.new_view(|cx| TestItem::new(label, cx));
+        pane.add_item(Box::new(item.clone()), true, true, None, cx);
+        item
+    })
+}
+
+/// Returns the labels of the pane items, in order.
+pub fn item_labels(pane: &View<Pane>, cx: &mut VisualTestContext) -> Vec<SharedString> {
+    pane.update(cx, |pane, cx| {
+        pane.items()
+            .filter_map(|item| item.downcast::<TestItem>())
+            .map(|item| item.read(cx).label.clone())
+            .collect()
+    })
+}
+
+/// Returns the label of the active item of the pane.
+pub fn active_label(pane: &View<Pane>, cx: &mut VisualTestContext) -> Option<SharedString> {
+    pane.update(cx, |pane, cx| {
+        let item = pane.active_item()?.downcast::<TestItem>()?;
+        Some(item.read(cx).label.clone())
+    })
+}
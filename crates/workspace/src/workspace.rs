@@ -7,15 +7,18 @@ use std::{
 
 use crate::{
     dock::{Panel, PanelHandle},
+    item::ItemHandle,
     pane_group,
+    util::ResultExt,
+    WorkspaceStore,
 };
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use gpui::{
-    actions, canvas, div, impl_actions, prelude::FluentBuilder as _, AnyWeakView, AppContext,
-    Bounds, Div, DragMoveEvent, Entity as _, EntityId, EventEmitter, FocusHandle, FocusableView,
-    InteractiveElement as _, IntoElement, KeyContext, ParentElement as _, Pixels, Point, Render,
-    Styled as _, Subscription, Task, View, ViewContext, VisualContext as _, WeakView,
-    WindowContext,
+    actions, canvas, div, impl_actions, prelude::FluentBuilder as _, AnyWeakView, AnyWindowHandle,
+    AppContext, Bounds, Div, DragMoveEvent, Entity as _, EntityId, EventEmitter, FocusHandle,
+    FocusableView, InteractiveElement as _, IntoElement, KeyContext, ParentElement as _, Pixels,
+    Point, Render, SharedString, Styled as _, Subscription, Task, View, ViewContext,
+    VisualContext as _, WeakView, WindowContext,
 };
 use serde::Deserialize;
 use ti::{h_flex, theme::ActiveTheme};
@@ -23,7 +26,7 @@ use ti::{h_flex, theme::ActiveTheme};
 use super::{
     dock::{Dock, DockPosition},
     pane::{self, Pane},
-    pane_group::{PaneGroup, SplitDirection},
+    pane_group::{PaneGroup, PaneLayoutSpec, SplitDirection},
 };
 
 actions!(
@@ -40,6 +43,7 @@ actions!(
         CloseAllItemsAndPanes,
         CloseInactiveTabsAndPanes,
         ReopenClosedItem,
+        NewWindow,
     ]
 );
 
@@ -52,9 +56,23 @@ pub struct ActivatePaneInDirection(pub SplitDirection);
 #[derive(Clone, Deserialize, PartialEq)]
 pub struct SwapPaneInDirection(pub SplitDirection);
 
+/// Activate the window with the given id, see [`WorkspaceStore::window_id`].
+#[derive(Clone, Deserialize, PartialEq)]
+pub struct ActivateWindow(pub u64);
+
+/// Move the active item to the window with the given id, see [`WorkspaceStore::window_id`].
+#[derive(Clone, Deserialize, PartialEq)]
+pub struct MoveItemToWindow(pub u64);
+
 impl_actions!(
     workspace,
-    [ActivatePane, ActivatePaneInDirection, SwapPaneInDirection,]
+    [
+        ActivatePane,
+        ActivatePaneInDirection,
+        SwapPaneInDirection,
+        ActivateWindow,
+        MoveItemToWindow,
+    ]
 );
 
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -74,6 +92,7 @@ pub struct Workspace {
     right_dock: View<Dock>,
     panes: Vec<View<Pane>>,
     pub(crate) panes_by_item: HashMap<EntityId, WeakView<Pane>>,
+    pub(crate) item_subscriptions: HashMap<EntityId, Vec<Subscription>>,
     active_pane: View<Pane>,
     last_active_center_pane: Option<WeakView<Pane>>,
     pub(crate) zoomed: Option<AnyWeakView>,
@@ -91,8 +110,16 @@ pub enum Event {
     ItemAdded,
     ItemRemoved,
     ActiveItemChanged,
+    /// Another pane was focused, so the active item may have changed without
+    /// [`Event::ActiveItemChanged`].
+    ActivePaneChanged,
     WorkspaceCreated(WeakView<Workspace>),
     ZoomChanged,
+    /// An item was dragged out of every window, `position` is the drop point in screen coordinates.
+    ItemTornOff {
+        item: Box<dyn ItemHandle>,
+        position: Point<Pixels>,
+    },
 }
 
 impl EventEmitter<Event> for Workspace {}
@@ -261,6 +288,7 @@ impl Workspace {
         // let window_handle = cx.window_handle().downcast::<Workspace>().unwrap();
 
         cx.emit(Event::WorkspaceCreated(weak_handle.clone()));
+        WorkspaceStore::register(cx.window_handle(), weak_handle.clone(), cx);
         let left_dock = Dock::new(DockPosition::Left, cx);
         let bottom_dock = Dock::new(DockPosition::Bottom, cx);
         let right_dock = Dock::new(DockPosition::Right, cx);
@@ -311,12 +339,9 @@ impl Workspace {
                 this.serialize_workspace(cx);
                 cx.notify();
             }),
-            // cx.on_release(|this, window, cx| {
-            //     this.app_state.workspace_store.update(cx, |store, _| {
-            //         let window = window.downcast::<Self>().unwrap();
-            //         store.workspaces.remove(&window);
-            //     })
-            // }),
+            cx.on_release(|this, _, cx| {
+                WorkspaceStore::unregister(this.weak_self.entity_id(), cx);
+            }),
         ];
 
         Workspace {
@@ -326,6 +351,7 @@ impl Workspace {
             center: PaneGroup::new(center_pane.clone()),
             panes: vec![center_pane.clone()],
             panes_by_item: Default::default(),
+            item_subscriptions: Default::default(),
             active_pane: center_pane.clone(),
             last_active_center_pane: Some(center_pane.downgrade()),
             left_dock,
@@ -415,6 +441,7 @@ impl Workspace {
                 }),
             )
             .on_action(cx.listener(Workspace::activate_pane_at_index))
+            .on_action(cx.listener(Workspace::move_active_item_to_window))
             .on_action(
                 cx.listener(|_workspace: &mut Workspace, _: &ReopenClosedItem, _cx| {
                     // workspace.reopen_closed_item(cx).detach();
@@ -614,12 +641,111 @@ impl Workspace {
         });
     }
 
+    /// Move an item into the workspace of another window.
+    ///
+    /// The item goes to the center pane under `position` (in screen coordinates),
+    /// or the active pane of that workspace.
+    pub fn move_item_to_window(
+        &mut self,
+        source: View<Pane>,
+        item_id: EntityId,
+        window: AnyWindowHandle,
+        workspace: WeakView<Workspace>,
+        position: Option<Point<Pixels>>,
+        cx: &mut ViewContext<Self>,
+    ) -> Result<()> {
+        let item = Self::take_item(&source, item_id, cx)
+            .ok_or_else(|| anyhow!("item {item_id} is not in the source pane"))?;
+
+        window.update(cx, |_, cx| {
+            workspace.update(cx, |workspace, cx| {
+                workspace.add_item_at_position(item, position, cx)
+            })
+        })?
+    }
+
+    fn move_active_item_to_window(
+        &mut self,
+        action: &MoveItemToWindow,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some((window, workspace)) = WorkspaceStore::workspace_for_window(action.0, cx) else {
+            return;
+        };
+        if window == cx.window_handle() {
+            return;
+        }
+        let Some(item) = self.active_pane.read(cx).active_item() else {
+            return;
+        };
+
+        self.move_item_to_window(
+            self.active_pane.clone(),
+            item.item_id(),
+            window,
+            workspace,
+            None,
+            cx,
+        )
+        .log_err();
+    }
+
+    /// Remove an item so it can be opened in a new window, see [`Event::ItemTornOff`].
+    pub fn tear_off_item(
+        &mut self,
+        source: View<Pane>,
+        item_id: EntityId,
+        position: Point<Pixels>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let Some(item) = Self::take_item(&source, item_id, cx) {
+            cx.emit(Event::ItemTornOff { item, position });
+        }
+    }
+
+    fn take_item(
+        source: &View<Pane>,
+        item_id: EntityId,
+        cx: &mut ViewContext<Self>,
+    ) -> Option<Box<dyn ItemHandle>> {
+        source.update(cx, |pane, cx| {
+            let (ix, item) = pane
+                .items()
+                .enumerate()
+                .find(|(_, item)| item.item_id() == item_id)
+                .map(|(ix, item)| (ix, item.boxed_clone()))?;
+            pane.remove_item(ix, false, cx);
+            Some(item)
+        })
+    }
+
+    fn add_item_at_position(
+        &mut self,
+        item: Box<dyn ItemHandle>,
+        position: Option<Point<Pixels>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let pane = position
+            .and_then(|position| {
+                let position = position - cx.bounds().origin;
+                self.center.pane_at_pixel_position(position).cloned()
+            })
+            .unwrap_or_else(|| self.active_pane.clone());
+
+        pane.update(cx, |pane, cx| {
+            pane.add_item(item, true, true, None, cx);
+            pane.focus(cx);
+        });
+        cx.activate_window();
+    }
+
     fn remove_pane(&mut self, pane: View<Pane>, cx: &mut ViewContext<Self>) {
         if self.center.remove(&pane).unwrap() {
             self.force_remove_pane(&pane, cx);
 
             for removed_item in pane.read(cx).items() {
                 self.panes_by_item.remove(&removed_item.item_id());
+                self.item_subscriptions.remove(&removed_item.item_id());
             }
 
             cx.notify();
@@ -648,6 +774,83 @@ impl Workspace {
         &self.active_pane
     }
 
+    /// The title of the active item, used to tell windows apart.
+    pub fn title(&self, cx: &AppContext) -> Option<SharedString> {
+        self.active_pane.read(cx).active_item()?.tab_title(cx)
+    }
+
+    pub fn center(&self) -> &PaneGroup {
+        &self.center
+    }
+
+    /// Replace the center panes with the given layout.
+    ///
+    /// Panes referenced by id are reused and `None` leaves get a new, empty pane.
+    /// The items of panes missing from the layout are moved into its first pane.
+    pub fn set_center_layout(
+        &mut self,
+        layout: impl Into<PaneLayoutSpec>,
+        cx: &mut ViewContext<Self>,
+    ) -> Result<()> {
+        let layout = layout.into();
+        layout.validate()?;
+        for pane_id in layout.pane_ids() {
+            if !self.panes.iter().any(|pane| pane.entity_id() == pane_id) {
+                return Err(anyhow!("Pane {:?} not found", pane_id));
+            }
+        }
+
+        let center = PaneGroup::from_layout(&layout, &mut |pane_id| match pane_id {
+            Some(pane_id) => self
+                .panes
+                .iter()
+                .find(|pane| pane.entity_id() == pane_id)
+                .cloned()
+                .ok_or_else(|| anyhow!("Pane {:?} not found", pane_id)),
+            None => Ok(self.add_pane(cx)),
+        })?;
+
+        let kept_panes = center.panes().into_iter().cloned().collect::<Vec<_>>();
+        let first_pane = center.first_pane();
+        let removed_panes = self
+            .panes
+            .iter()
+            .filter(|pane| !kept_panes.contains(pane))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        self.center = center;
+        self.panes.retain(|pane| kept_panes.contains(pane));
+
+        for pane in removed_panes {
+            let items = pane.read(cx).items().cloned().collect::<Vec<_>>();
+            for item in items {
+                first_pane.update(cx, |first_pane, cx| {
+                    first_pane.add_item(item, false, false, None, cx)
+                });
+            }
+
+            if self.zoomed == Some(pane.downgrade().into()) {
+                self.zoomed = None;
+                self.zoomed_position = None;
+                cx.emit(Event::ZoomChanged);
+            }
+            if self.last_active_center_pane == Some(pane.downgrade()) {
+                self.last_active_center_pane = None;
+            }
+            if self.active_pane == pane {
+                self.active_pane = first_pane.clone();
+                cx.emit(Event::ActivePaneChanged);
+            }
+            cx.emit(Event::PaneRemoved);
+        }
+
+        self.active_pane.update(cx, |pane, cx| pane.focus(cx));
+        cx.notify();
+        self.serialize_workspace(cx);
+        Ok(())
+    }
+
     // pub fn reopen_closed_item(&mut self, cx: &mut ViewContext<Workspace>) -> Task<Result<()>> {
     //     self.navigate_history(
     //         self.active_pane().downgrade(),
@@ -827,6 +1030,8 @@ impl Workspace {
         if self.active_pane != pane {
             self.active_pane = pane.clone();
             self.last_active_center_pane = Some(pane.downgrade());
+            cx.emit(Event::ActivePaneChanged);
+            WorkspaceStore::title_changed(cx);
         }
 
         self.dismiss_zoomed_items_to_reveal(None, cx);
@@ -858,16 +1063,21 @@ impl Workspace {
             pane::Event::Remove => self.remove_pane(pane, cx),
             pane::Event::ActivateItem { local: _ } => {
                 cx.emit(Event::ActiveItemChanged);
+                WorkspaceStore::title_changed(cx);
             }
             pane::Event::ChangeItemTitle => {
                 cx.emit(Event::ActiveItemChanged);
+                WorkspaceStore::title_changed(cx);
             }
             pane::Event::RemoveItem { item_id } => {
+                cx.emit(Event::ItemRemoved);
                 cx.emit(Event::ActiveItemChanged);
+                WorkspaceStore::title_changed(cx);
 
                 if let hash_map::Entry::Occupied(entry) = self.panes_by_item.entry(*item_id) {
                     if entry.get().entity_id() == pane.entity_id() {
                         entry.remove();
+                        self.item_subscriptions.remove(item_id);
                     }
                 }
             }
@@ -1005,3 +1215,383 @@ impl Workspace {
         // }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use gpui::{Axis, TestAppContext, VisualTestContext};
+
+    use super::*;
+    use crate::pane_group::PaneLayout;
+    use crate::test::{add_test_item, build_workspace, init_test, item_labels, TestPanel};
+
+    #[gpui::test]
+    fn test_split_pane(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let left = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        add_test_item(&left, "A", cx);
+
+        let right = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(left.clone(), SplitDirection::Right, cx)
+        });
+        let top = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(right.clone(), SplitDirection::Up, cx)
+        });
+
+        workspace.update(cx, |workspace, _| {
+            assert_eq!(workspace.panes().len(), 3);
+            assert_eq!(
+                workspace.center().layout(),
+                PaneLayout::axis(
+                    Axis::Horizontal,
+                    vec![
+                        PaneLayout::Pane(left.entity_id()),
+                        PaneLayout::axis(
+                            Axis::Vertical,
+                            vec![
+                                PaneLayout::Pane(top.entity_id()),
+                                PaneLayout::Pane(right.entity_id()),
+                            ]
+                        ),
+                    ]
+                )
+            );
+        });
+
+        let bottom = workspace
+            .update(cx, |workspace, cx| {
+                workspace.split_and_clone(left.clone(), SplitDirection::Down, cx)
+            })
+            .unwrap();
+        assert_eq!(item_labels(&bottom, cx), ["A"]);
+        assert_eq!(item_labels(&left, cx), ["A"]);
+    }
+
+    #[gpui::test]
+    fn test_closing_inactive_item_emits_item_removed(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        let a = add_test_item(&pane, "A", cx);
+        add_test_item(&pane, "B", cx);
+
+        let removed = Rc::new(Cell::new(0));
+        cx.update(|cx| {
+            let removed = removed.clone();
+            cx.subscribe(&workspace, move |_, event: &Event, _| {
+                if matches!(event, Event::ItemRemoved) {
+                    removed.set(removed.get() + 1);
+                }
+            })
+            .detach();
+        });
+
+        pane.update(cx, |pane, cx| {
+            pane.close_item_by_id(a.entity_id(), cx)
+                .detach_and_log_err(cx)
+        });
+        cx.run_until_parked();
+
+        assert_eq!(item_labels(&pane, cx), ["B"]);
+        assert_eq!(removed.get(), 1);
+    }
+
+    #[gpui::test]
+    fn test_focusing_pane_emits_active_pane_changed(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let left = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        add_test_item(&left, "A", cx);
+        let right = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(left.clone(), SplitDirection::Right, cx)
+        });
+        add_test_item(&right, "B", cx);
+        right.update(cx, |pane, cx| pane.focus(cx));
+        cx.run_until_parked();
+
+        let changed = Rc::new(Cell::new(0));
+        cx.update(|cx| {
+            let changed = changed.clone();
+            cx.subscribe(&workspace, move |_, event: &Event, _| {
+                if matches!(event, Event::ActivePaneChanged) {
+                    changed.set(changed.get() + 1);
+                }
+            })
+            .detach();
+        });
+
+        left.update(cx, |pane, cx| pane.focus(cx));
+        cx.run_until_parked();
+        workspace.update(cx, |workspace, _| assert!(workspace.active_pane() == &left));
+        assert_eq!(changed.get(), 1);
+
+        // Focusing the active pane again changes nothing.
+        left.update(cx, |pane, cx| pane.focus(cx));
+        cx.run_until_parked();
+        assert_eq!(changed.get(), 1);
+    }
+
+    #[gpui::test]
+    fn test_closing_last_item_removes_pane(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let left = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        add_test_item(&left, "A", cx);
+        let right = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(left.clone(), SplitDirection::Right, cx)
+        });
+        let b = add_test_item(&right, "B", cx);
+
+        right.update(cx, |pane, cx| {
+            pane.close_item_by_id(b.entity_id(), cx)
+                .detach_and_log_err(cx)
+        });
+        cx.run_until_parked();
+
+        workspace.update(cx, |workspace, _| {
+            assert!(workspace.panes() == [left.clone()]);
+            assert_eq!(
+                workspace.center().layout(),
+                PaneLayout::Pane(left.entity_id())
+            );
+            assert!(!workspace.panes_by_item.contains_key(&b.entity_id()));
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_item(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let left = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        let a = add_test_item(&left, "A", cx);
+        let b = add_test_item(&left, "B", cx);
+        let right = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(left.clone(), SplitDirection::Right, cx)
+        });
+
+        workspace.update(cx, |workspace, cx| {
+            workspace.move_item(left.clone(), right.clone(), b.entity_id(), 0, cx)
+        });
+        cx.run_until_parked();
+        assert_eq!(item_labels(&left, cx), ["A"]);
+        assert_eq!(item_labels(&right, cx), ["B"]);
+        workspace.update(cx, |workspace, _| {
+            let pane = workspace.panes_by_item[&b.entity_id()].upgrade();
+            assert!(pane == Some(right.clone()));
+        });
+
+        // Moving the last item out of a pane removes the pane.
+        workspace.update(cx, |workspace, cx| {
+            workspace.move_item(left.clone(), right.clone(), a.entity_id(), 1, cx)
+        });
+        cx.run_until_parked();
+        assert_eq!(item_labels(&right, cx), ["B", "A"]);
+        workspace.update(cx, |workspace, _| {
+            assert!(workspace.panes() == [right.clone()]);
+            assert_eq!(
+                workspace.center().layout(),
+                PaneLayout::Pane(right.entity_id())
+            );
+            let pane = workspace.panes_by_item[&a.entity_id()].upgrade();
+            assert!(pane == Some(right.clone()));
+        });
+    }
+
+    #[gpui::test]
+    fn test_swap_panes(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let left = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        let right = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(left.clone(), SplitDirection::Right, cx)
+        });
+
+        workspace.update(cx, |workspace, _| {
+            workspace.center.swap(&left, &right);
+            assert_eq!(
+                workspace.center().layout().pane_ids(),
+                [right.entity_id(), left.entity_id()]
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_set_center_layout(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let left = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        add_test_item(&left, "A", cx);
+        let right = workspace.update(cx, |workspace, cx| {
+            workspace.split_pane(left.clone(), SplitDirection::Right, cx)
+        });
+        add_test_item(&right, "B", cx);
+
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace
+                .set_center_layout(
+                    PaneLayout::axis(Axis::Vertical, vec![PaneLayout::Pane(right.entity_id())]),
+                    cx
+                )
+                .is_err());
+
+            workspace
+                .set_center_layout(
+                    PaneLayoutSpec::Axis {
+                        axis: Axis::Vertical,
+                        flexes: vec![1., 3.],
+                        members: vec![
+                            PaneLayout::Pane(Some(right.entity_id())),
+                            PaneLayout::Pane(None),
+                        ],
+                    },
+                    cx,
+                )
+                .unwrap();
+        });
+        cx.run_until_parked();
+
+        // The items of the left pane are moved to the first pane of the layout.
+        assert_eq!(item_labels(&right, cx), ["B", "A"]);
+        workspace.update(cx, |workspace, _| {
+            assert_eq!(workspace.panes().len(), 2);
+            assert!(!workspace.panes().contains(&left));
+            let new_pane = workspace
+                .panes()
+                .iter()
+                .find(|pane| **pane != right)
+                .unwrap()
+                .clone();
+            assert_eq!(
+                workspace.center().layout(),
+                PaneLayout::Axis {
+                    axis: Axis::Vertical,
+                    flexes: vec![0.5, 1.5],
+                    members: vec![
+                        PaneLayout::Pane(right.entity_id()),
+                        PaneLayout::Pane(new_pane.entity_id()),
+                    ],
+                }
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_zoom_pane(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let pane = workspace.update(cx, |workspace, _| workspace.active_pane().clone());
+        add_test_item(&pane, "A", cx);
+        pane.update(cx, |pane, cx| pane.focus(cx));
+        cx.run_until_parked();
+
+        pane.update(cx, |_, cx| cx.emit(pane::Event::ZoomIn));
+        cx.run_until_parked();
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.zoomed == Some(pane.downgrade().into()));
+            assert!(pane.read(cx).is_zoomed());
+        });
+
+        pane.update(cx, |_, cx| cx.emit(pane::Event::ZoomOut));
+        cx.run_until_parked();
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.zoomed.is_none());
+            assert!(!pane.read(cx).is_zoomed());
+        });
+    }
+
+    #[gpui::test]
+    fn test_toggle_dock(cx: &mut TestAppContext) {
+        let (workspace, cx) = build_workspace(cx);
+        let panel = workspace.update(cx, |workspace, cx| {
+            let panel = cx.new_view(|cx| TestPanel::new(DockPosition::Left, cx));
+            workspace.add_panel(panel.clone(), cx);
+            panel
+        });
+
+        workspace.update(cx, |workspace, cx| {
+            assert!(workspace.left_dock().read(cx).is_open());
+            assert!(panel.read(cx).active);
+
+            workspace.toggle_dock(DockPosition::Left, cx);
+            assert!(!workspace.left_dock().read(cx).is_open());
+            assert!(!panel.read(cx).active);
+
+            workspace.toggle_dock(DockPosition::Left, cx);
+            assert!(workspace.left_dock().read(cx).is_open());
+            assert!(panel.read(cx).active);
+
+            workspace.close_all_docks(cx);
+            assert!(!workspace.left_dock().read(cx).is_open());
+            assert!(!workspace.right_dock().read(cx).is_open());
+            assert!(!workspace.bottom_dock().read(cx).is_open());
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_item_to_window(cx: &mut TestAppContext) {
+        init_test(cx);
+        let window_a = cx.add_window(|cx| Workspace::new(None, cx));
+        let window_b = cx.add_window(|cx| Workspace::new(None, cx));
+        let workspace_a = cx.update(|cx| window_a.root_view(cx)).unwrap();
+        let workspace_b = cx.update(|cx| window_b.root_view(cx)).unwrap();
+        cx.update(|cx| assert_eq!(WorkspaceStore::workspaces(cx).len(), 2));
+
+        let cx = &mut VisualTestContext::from_window(window_a.into(), cx);
+        let pane_a = workspace_a.update(cx, |workspace, _| workspace.active_pane().clone());
+        let pane_b = workspace_b.update(cx, |workspace, _| workspace.active_pane().clone());
+        let a = add_test_item(&pane_a, "A", cx);
+        add_test_item(&pane_a, "B", cx);
+
+        workspace_a
+            .update(cx, |workspace, cx| {
+                workspace.move_item_to_window(
+                    pane_a.clone(),
+                    a.entity_id(),
+                    window_b.into(),
+                    workspace_b.downgrade(),
+                    None,
+                    cx,
+                )
+            })
+            .unwrap();
+        cx.run_until_parked();
+
+        assert_eq!(item_labels(&pane_a, cx), ["B"]);
+        assert_eq!(item_labels(&pane_b, cx), ["A"]);
+        workspace_a.update(cx, |workspace, _| {
+            assert!(!workspace.panes_by_item.contains_key(&a.entity_id()));
+            assert!(!workspace.item_subscriptions.contains_key(&a.entity_id()));
+        });
+        workspace_b.update(cx, |workspace, _| {
+            assert!(workspace.panes_by_item.contains_key(&a.entity_id()));
+            assert!(workspace.item_subscriptions.contains_key(&a.entity_id()));
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_item_to_window_by_id(cx: &mut TestAppContext) {
+        init_test(cx);
+        let window_a = cx.add_window(|cx| Workspace::new(None, cx));
+        let window_b = cx.add_window(|cx| Workspace::new(None, cx));
+        let window_c = cx.add_window(|cx| Workspace::new(None, cx));
+        let workspace_b = cx.update(|cx| window_b.root_view(cx)).unwrap();
+        let workspace_c = cx.update(|cx| window_c.root_view(cx)).unwrap();
+        let target = WorkspaceStore::window_id(window_c.into());
+
+        // Closing a window shifts the positions of the others, but not their ids.
+        window_a.update(cx, |_, cx| cx.remove_window()).unwrap();
+        cx.run_until_parked();
+        cx.update(|cx| {
+            assert_eq!(WorkspaceStore::workspaces(cx).len(), 2);
+            assert!(WorkspaceStore::workspace_for_window(target, cx).is_some());
+        });
+
+        let cx = &mut VisualTestContext::from_window(window_b.into(), cx);
+        let pane_b = workspace_b.update(cx, |workspace, _| workspace.active_pane().clone());
+        let pane_c = workspace_c.update(cx, |workspace, _| workspace.active_pane().clone());
+        add_test_item(&pane_b, "A", cx);
+        add_test_item(&pane_b, "B", cx);
+
+        workspace_b.update(cx, |workspace, cx| {
+            workspace.move_active_item_to_window(&MoveItemToWindow(target), cx)
+        });
+        cx.run_until_parked();
+
+        assert_eq!(item_labels(&pane_b, cx), ["A"]);
+        assert_eq!(item_labels(&pane_c, cx), ["B"]);
+    }
+}
@@ -0,0 +1,121 @@
+use gpui::{
+    AnyWindowHandle, AppContext, Context as _, Entity as _, EntityId, Global, Model, Pixels, Point,
+    WeakView,
+};
+
+use crate::{util::ResultExt, Workspace};
+
+/// What to do when the last workspace window is closed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LastWindowClosed {
+    /// Quit the application.
+    #[default]
+    Quit,
+    /// Keep the application running without windows.
+    KeepRunning,
+}
+
+/// Tracks the workspace of every open window, so items can move between windows.
+#[derive(Default)]
+pub struct WorkspaceStore {
+    workspaces: Vec<(AnyWindowHandle, WeakView<Workspace>)>,
+    last_window_closed: LastWindowClosed,
+}
+
+struct GlobalWorkspaceStore(Model<WorkspaceStore>);
+
+impl Global for GlobalWorkspaceStore {}
+
+impl WorkspaceStore {
+    /// Returns the store, creating it on first use.
+    pub fn global(cx: &mut AppContext) -> Model<Self> {
+        if let Some(store) = cx.try_global::<GlobalWorkspaceStore>() {
+            return store.0.clone();
+        }
+
+        let store = cx.new_model(|_| Self::default());
+        cx.set_global(GlobalWorkspaceStore(store.clone()));
+        store
+    }
+
+    pub(crate) fn register(
+        window: AnyWindowHandle,
+        workspace: WeakView<Workspace>,
+        cx: &mut AppContext,
+    ) {
+        Self::global(cx).update(cx, |store, cx| {
+            store.workspaces.push((window, workspace));
+            cx.notify();
+        });
+    }
+
+    pub(crate) fn unregister(workspace_id: EntityId, cx: &mut AppContext) {
+        let should_quit = Self::global(cx).update(cx, |store, cx| {
+            store
+                .workspaces
+                .retain(|(_, workspace)| workspace.entity_id() != workspace_id);
+            cx.notify();
+            store.workspaces.is_empty() && store.last_window_closed == LastWindowClosed::Quit
+        });
+
+        if should_quit {
+            cx.quit();
+        }
+    }
+
+    /// Set what happens when the last workspace window is closed, quit by default.
+    pub fn set_last_window_closed(&mut self, behavior: LastWindowClosed) {
+        self.last_window_closed = behavior;
+    }
+
+    /// Tell the observers of the store that the title of a workspace may have changed.
+    pub(crate) fn title_changed(cx: &mut AppContext) {
+        Self::global(cx).update(cx, |_, cx| cx.notify());
+    }
+
+    /// Bring the window with the given id to front, see [`Self::window_id`].
+    pub fn activate_window(window_id: u64, cx: &mut AppContext) {
+        if let Some((window, _)) = Self::workspace_for_window(window_id, cx) {
+            window.update(cx, |_, cx| cx.activate_window()).log_err();
+        }
+    }
+
+    /// The id of a window, which stays the same while other windows open and close.
+    pub fn window_id(window: AnyWindowHandle) -> u64 {
+        window.window_id().as_u64()
+    }
+
+    /// Returns the window with the given id, see [`Self::window_id`], and its workspace.
+    pub fn workspace_for_window(
+        window_id: u64,
+        cx: &AppContext,
+    ) -> Option<(AnyWindowHandle, WeakView<Workspace>)> {
+        Self::workspaces(cx)
+            .into_iter()
+            .find(|(window, _)| Self::window_id(*window) == window_id)
+    }
+
+    /// Returns the open workspaces along with their windows.
+    pub fn workspaces(cx: &AppContext) -> Vec<(AnyWindowHandle, WeakView<Workspace>)> {
+        cx.try_global::<GlobalWorkspaceStore>()
+            .map(|store| store.0.read(cx).workspaces.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the workspace of the window at the given screen position, ignoring `except`.
+    pub fn workspace_at_position(
+        position: Point<Pixels>,
+        except: AnyWindowHandle,
+        cx: &mut AppContext,
+    ) -> Option<(AnyWindowHandle, WeakView<Workspace>)> {
+        Self::workspaces(cx)
+            .into_iter()
+            .filter(|(window, _)| *window != except)
+            .find(|(window, workspace)| {
+                workspace.upgrade().is_some()
+                    && window
+                        .update(cx, |_, cx| cx.bounds().contains(&position))
+                        .unwrap_or(false)
+            })
+    }
+}